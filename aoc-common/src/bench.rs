@@ -0,0 +1,183 @@
+//! Repeated timing of the parsing and solving steps of a [`Puzzle`].
+//!
+//! Baselines are stored as tab-separated text, one step per line:
+//!
+//! ```text
+//! day    step    median_ms
+//! 6      part2   1534.210512
+//! ```
+
+use std::{
+    cmp::Reverse,
+    collections::HashMap,
+    fmt::Display,
+    fs,
+    hint::black_box,
+    io::{self, Write},
+    path::Path,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use crate::{error::ParseResult, puzzle::Puzzle};
+
+/// The parts of a day's solution that are timed separately
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Step {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Step::Parse => "parse",
+            Step::Part1 => "part1",
+            Step::Part2 => "part2",
+        };
+
+        write!(f, "{name}")
+    }
+}
+
+impl FromStr for Step {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "parse" => Ok(Step::Parse),
+            "part1" => Ok(Step::Part1),
+            "part2" => Ok(Step::Part2),
+            _ => Err(format!("Unknown step '{s}'. Expected one of: parse, part1, part2")),
+        }
+    }
+}
+
+/// Summary of the times taken by a number of runs of the same step
+#[derive(Debug, Clone, Copy)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>) -> Stats {
+        assert!(!samples.is_empty(), "Can't summarize zero runs");
+
+        samples.sort();
+        let runs = samples.len();
+
+        Stats {
+            runs,
+            min: samples[0],
+            median: samples[runs / 2],
+            mean: samples.iter().sum::<Duration>() / runs as u32,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Measurement {
+    pub day: u8,
+    pub step: Step,
+    pub stats: Stats,
+}
+
+fn time_runs<F: FnMut()>(runs: usize, mut f: F) -> Stats {
+    let samples = (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            f();
+            start.elapsed()
+        })
+        .collect();
+
+    Stats::from_samples(samples)
+}
+
+/// Times every step of the puzzle `runs` times. The parts are timed on an input parsed beforehand,
+/// so they don't include the parsing time.
+pub fn measure<P: Puzzle>(day: u8, input: &str, runs: usize) -> ParseResult<Vec<Measurement>> {
+    let parsed = P::parse(input)?;
+    let measurement = |step, stats| Measurement { day, step, stats };
+
+    Ok(vec![
+        measurement(Step::Parse, time_runs(runs, || { black_box(P::parse(black_box(input))).ok(); })),
+        measurement(Step::Part1, time_runs(runs, || { black_box(P::part1(black_box(&parsed))); })),
+        measurement(Step::Part2, time_runs(runs, || { black_box(P::part2(black_box(&parsed))); })),
+    ])
+}
+
+/// Median times of a previous benchmark run, to compare against
+pub type Baseline = HashMap<(u8, Step), Duration>;
+
+pub fn read_baseline(path: &Path) -> Result<Baseline, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Can't read {}: {e}", path.display()))?;
+    let bad_line = |n: usize| format!("{}:{}: expected 'day<TAB>step<TAB>median_ms'", path.display(), n + 1);
+
+    content.lines()
+        .enumerate()
+        .filter(|(_, l)| !l.trim().is_empty() && !l.starts_with("day\t"))
+        .map(|(n, l)| {
+            let fields = l.split('\t').collect::<Vec<_>>();
+            if fields.len() != 3 {
+                return Err(bad_line(n));
+            }
+
+            let day = fields[0].parse::<u8>().map_err(|_| bad_line(n))?;
+            let step = fields[1].parse::<Step>().map_err(|_| bad_line(n))?;
+            let ms = fields[2].parse::<f64>().ok().filter(|ms| *ms >= 0.0).ok_or_else(|| bad_line(n))?;
+
+            Ok(((day, step), Duration::from_secs_f64(ms / 1000.0)))
+        })
+        .collect()
+}
+
+fn millis(d: Duration) -> f64 {
+    d.as_secs_f64() * 1000.0
+}
+
+pub fn write_baseline<W: Write>(out: &mut W, measurements: &[Measurement]) -> io::Result<()> {
+    writeln!(out, "day\tstep\tmedian_ms")?;
+    for m in measurements {
+        writeln!(out, "{}\t{}\t{:.6}", m.day, m.step, millis(m.stats.median))?;
+    }
+
+    Ok(())
+}
+
+/// Prints the measurements as a table, slowest steps first. With a baseline, the relative
+/// change of every median is shown too.
+pub fn write_table<W: Write>(out: &mut W, measurements: &[Measurement], baseline: Option<&Baseline>) -> io::Result<()> {
+    let mut sorted = measurements.iter().collect::<Vec<_>>();
+    sorted.sort_by_key(|m| Reverse(m.stats.median));
+
+    write!(out, "{:>3}  {:<5}  {:>4}  {:>12}  {:>12}  {:>12}", "day", "step", "runs", "min ms", "median ms", "mean ms")?;
+    if baseline.is_some() {
+        write!(out, "  {:>12}  {:>8}", "baseline ms", "change")?;
+    }
+    writeln!(out)?;
+
+    for m in sorted {
+        write!(out, "{:>3}  {:<5}  {:>4}  {:>12.3}  {:>12.3}  {:>12.3}",
+            m.day, m.step, m.stats.runs, millis(m.stats.min), millis(m.stats.median), millis(m.stats.mean))?;
+
+        if let Some(baseline) = baseline {
+            match baseline.get(&(m.day, m.step)) {
+                Some(&before) if !before.is_zero() => {
+                    let change = (millis(m.stats.median) / millis(before) - 1.0) * 100.0;
+                    write!(out, "  {:>12.3}  {:>+7.1}%", millis(before), change)?;
+                }
+                Some(&before) => write!(out, "  {:>12.3}  {:>8}", millis(before), "-")?,
+                None => write!(out, "  {:>12}  {:>8}", "-", "-")?,
+            }
+        }
+        writeln!(out)?;
+    }
+
+    Ok(())
+}
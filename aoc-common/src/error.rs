@@ -0,0 +1,81 @@
+use std::{
+    fmt::Display,
+    io::BufRead,
+    process::exit,
+    str::FromStr,
+};
+
+/// Something unexpected found while parsing a puzzle input. Lines and columns start at 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: Option<String>,
+}
+
+pub type ParseResult<T> = Result<T, ParseError>;
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, expected: impl Into<String>) -> Self {
+        ParseError {
+            line,
+            column,
+            expected: expected.into(),
+            found: None,
+        }
+    }
+
+    pub fn found(self, found: impl Into<String>) -> Self {
+        ParseError {
+            found: Some(found.into()),
+            .. self
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}: expected {}", self.line, self.column, self.expected)?;
+
+        match &self.found {
+            Some(found) if found.is_empty() => write!(f, ", found nothing"),
+            Some(found) => write!(f, ", found '{found}'"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Column (1-based) where `part` starts within `line`. `part` must be a subslice of `line`,
+/// as the ones returned by `split`, `trim` and friends.
+pub fn column_of(line: &str, part: &str) -> usize {
+    let offset = (part.as_ptr() as usize).saturating_sub(line.as_ptr() as usize);
+
+    line[..offset.min(line.len())].chars().count() + 1
+}
+
+/// Parses `part` (a subslice of `line`, see [`column_of`]) into a number, or some other type
+/// implementing `FromStr`
+pub fn parse_at<T: FromStr>(line_no: usize, line: &str, part: &str, expected: &str) -> ParseResult<T> {
+    part.parse::<T>()
+        .map_err(|_| ParseError::new(line_no, column_of(line, part), expected).found(part))
+}
+
+/// Lines of the stream, numbered from 1. Reading errors (eg. invalid UTF-8) become parse errors.
+pub fn numbered_lines<R: BufRead>(stream: R) -> impl Iterator<Item = ParseResult<(usize, String)>> {
+    stream.lines()
+        .enumerate()
+        .map(|(n, l)| l
+            .map(|l| (n + 1, l))
+            .map_err(|e| ParseError::new(n + 1, 1, "a line of text").found(e.to_string())))
+}
+
+/// Unwraps the result of solving a puzzle, or prints a diagnostic and quits
+pub fn or_exit<T>(result: ParseResult<T>) -> T {
+    result.unwrap_or_else(|e| {
+        eprintln!("Invalid input at {e}");
+        exit(1);
+    })
+}
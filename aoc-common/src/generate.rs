@@ -0,0 +1,78 @@
+//! Random puzzle inputs, for stress tests.
+//!
+//! Every day has a `generate` module with an `input` function that writes a syntactically valid
+//! input out of a [`Rng`] and some [`Params`]. The same seed always gives the same input, on
+//! every platform, so the generator doesn't depend on any external crate.
+
+/// A small, seedable pseudo-random generator (SplitMix64). Not meant for anything but tests.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`. `n` can't be zero.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "Can't pick a number below zero");
+
+        // Good enough for tests: the bias is negligible for small ranges
+        self.next_u64() % n
+    }
+
+    /// A number in `low..=high`
+    pub fn between(&mut self, low: i64, high: i64) -> i64 {
+        assert!(low <= high, "Empty range {low}..={high}");
+
+        low + self.below((high - low) as u64 + 1) as i64
+    }
+
+    /// An index in `0..n`
+    pub fn index(&mut self, n: usize) -> usize {
+        self.below(n as u64) as usize
+    }
+
+    /// True with probability `p`
+    pub fn chance(&mut self, p: f64) -> bool {
+        let unit = (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+        unit < p
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
+
+/// What to generate. The meaning of `size` depends on the day (the side of a map, the number of
+/// lines...), but bigger is always bigger.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Params {
+    pub size: usize,
+    /// Make sure the input has a solution, for the days where it could have none (a guard that
+    /// never leaves the map, a claw machine that can't reach the prize...)
+    pub solvable: bool,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params { size: 100, solvable: true }
+    }
+}
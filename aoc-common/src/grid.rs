@@ -0,0 +1,219 @@
+use std::{
+    io::BufRead,
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    error::{numbered_lines, ParseError, ParseResult},
+    point::{Compass, Point},
+};
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid out of a list of rows. All the rows must have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |r| r.len());
+
+        assert!(rows.iter().all(|r| r.len() == width), "All the rows in a grid must have the same length");
+
+        Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    /// Reads rows from the stream until reaching the end or the first blank line, turning
+    /// every character into a cell with `f`, which returns a description of what it expected
+    /// for characters it doesn't accept. The blank line (if any) is consumed.
+    pub fn parse<R, F>(stream: R, mut f: F) -> ParseResult<Self>
+    where
+        R: BufRead,
+        F: FnMut(char) -> Result<T, String>,
+    {
+        let mut rows: Vec<Vec<T>> = vec![];
+
+        for line in numbered_lines(stream) {
+            let (line_no, line) = line?;
+            let line = line.trim_end();
+
+            if line.is_empty() {
+                break;
+            }
+
+            let row = line.chars()
+                .enumerate()
+                .map(|(col, c)| f(c).map_err(|exp| ParseError::new(line_no, col + 1, exp).found(c)))
+                .collect::<ParseResult<Vec<_>>>()?;
+
+            if let Some(first) = rows.first() {
+                if row.len() != first.len() {
+                    let column = row.len().min(first.len()) + 1;
+                    return Err(ParseError::new(line_no, column, format!("a row of {} cells", first.len()))
+                        .found(format!("{} cells", row.len())));
+                }
+            }
+
+            rows.push(row);
+        }
+
+        Ok(Grid::from_rows(rows))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        p.x >= 0 && p.y >= 0 && (p.x as usize) < self.width && (p.y as usize) < self.height
+    }
+
+    fn offset_of(&self, p: Point) -> Option<usize> {
+        if self.contains(p) {
+            Some(p.y as usize * self.width + p.x as usize)
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.offset_of(p).map(|off| &self.cells[off])
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.offset_of(p).map(|off| &mut self.cells[off])
+    }
+
+    /// Replaces the content of a cell, returning the old value. Out of bounds points are ignored.
+    pub fn set(&mut self, p: Point, value: T) -> Option<T> {
+        self.get_mut(p).map(|cell| std::mem::replace(cell, value))
+    }
+
+    /// All the points in the grid, in reading order
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| Point::from_row_col(row, col)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// Points of all the cells satisfying the predicate, in reading order
+    pub fn positions<'a, P>(&'a self, mut pred: P) -> impl Iterator<Item = Point> + 'a
+    where
+        P: FnMut(&T) -> bool + 'a,
+    {
+        self.iter().filter(move |(_, v)| pred(v)).map(|(p, _)| p)
+    }
+
+    /// In-bounds orthogonal neighbors of `p`
+    pub fn neighbors4(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        p.neighbors4().into_iter().filter(|n| self.contains(*n))
+    }
+
+    /// In-bounds orthogonal and diagonal neighbors of `p`
+    pub fn neighbors8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        p.neighbors8().into_iter().filter(|n| self.contains(*n))
+    }
+
+    /// Points starting at `start` (included) and moving towards `heading` until leaving the grid
+    pub fn ray(&self, start: Point, heading: Compass) -> impl Iterator<Item = Point> + '_ {
+        std::iter::successors(Some(start), move |p| Some(p.step_towards(heading)))
+            .take_while(|p| self.contains(*p))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks() won't accept a zero size
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width.max(1)).take(self.height)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    // A grid without any cells has no diagonals, whatever its size
+    fn diagonal_extent(&self) -> (usize, usize) {
+        if self.cells.is_empty() { (0, 0) } else { (self.width, self.height) }
+    }
+
+    /// Diagonals going down and right (`\`), starting from the top right corner
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let (width, height) = self.diagonal_extent();
+        let top = (0..width).rev().map(|x| Point::from_row_col(0, x));
+        let left = (1..height).map(|y| Point::from_row_col(y, 0));
+
+        top.chain(left).map(|start| self.ray(start, Compass::SE).map(|p| &self[p]))
+    }
+
+    /// Diagonals going down and left (`/`), starting from the top left corner
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let (width, height) = self.diagonal_extent();
+        let top = (0..width).map(|x| Point::from_row_col(0, x));
+        let right = (1..height).map(move |y| Point::from_row_col(y, width - 1));
+
+        top.chain(right).map(|start| self.ray(start, Compass::SW).map(|p| &self[p]))
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl Grid<char> {
+    /// Reads a plain character grid. See [`Grid::parse`]
+    pub fn parse_chars<R: BufRead>(stream: R) -> ParseResult<Self> {
+        Grid::parse(stream, Ok)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &Self::Output {
+        self.get(p).unwrap_or_else(|| panic!("{p:?} is out of the grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut Self::Output {
+        self.get_mut(p).unwrap_or_else(|| panic!("{p:?} is out of the grid"))
+    }
+}
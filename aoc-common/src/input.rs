@@ -0,0 +1,163 @@
+//! Locating puzzle inputs.
+//!
+//! Inputs are kept in a local directory (`inputs` by default), named after the day they belong to:
+//!
+//! ```text
+//! inputs/day06.txt            the puzzle input
+//! inputs/day06.example1.txt   the examples from the puzzle text
+//! ```
+//!
+//! An explicit path always wins. Without one, the puzzle input is looked up in the directory,
+//! falling back to the standard input when something is piped into it.
+
+use std::{
+    fs,
+    io::{read_to_string, stdin, IsTerminal, Read},
+    path::{Path, PathBuf},
+    process::exit,
+};
+
+pub const DEFAULT_DIR: &str = "inputs";
+
+/// Where an input is read from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    File(PathBuf),
+    Stdin,
+}
+
+/// What the user asked for, usually from the command line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub dir: PathBuf,
+    /// A file given explicitly. `-` stands for the standard input.
+    pub path: Option<PathBuf>,
+    pub example: Option<u32>,
+}
+
+impl Default for Request {
+    fn default() -> Self {
+        Request {
+            dir: PathBuf::from(DEFAULT_DIR),
+            path: None,
+            example: None,
+        }
+    }
+}
+
+pub fn puzzle_path(dir: &Path, day: u8) -> PathBuf {
+    dir.join(format!("day{day:02}.txt"))
+}
+
+pub fn example_path(dir: &Path, day: u8, example: u32) -> PathBuf {
+    dir.join(format!("day{day:02}.example{example}.txt"))
+}
+
+impl Request {
+    /// Looks for `--input FILE`, `--example K` and `--inputs DIR` among the program arguments
+    pub fn from_args() -> Result<Request, String> {
+        let mut request = Request::default();
+        let mut args = std::env::args();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--input" => request.path = Some(PathBuf::from(args.next().ok_or("--input needs a value")?)),
+                "--inputs" => request.dir = PathBuf::from(args.next().ok_or("--inputs needs a value")?),
+                "--example" => {
+                    let value = args.next().ok_or("--example needs a value")?;
+                    request.example = Some(value.parse().map_err(|_| format!("Not a valid example number: {value}"))?);
+                }
+                _ => {}
+            }
+        }
+
+        Ok(request)
+    }
+
+    /// Decides where the input of `day` comes from. `piped` tells whether the standard input
+    /// is usable as a fallback.
+    pub fn resolve(&self, day: u8, piped: bool) -> Result<Source, String> {
+        if let Some(path) = &self.path {
+            return if path.as_os_str() == "-" {
+                Ok(Source::Stdin)
+            } else if path.is_file() {
+                Ok(Source::File(path.clone()))
+            } else {
+                Err(format!("Input file {} doesn't exist", path.display()))
+            };
+        }
+
+        if let Some(example) = self.example {
+            let path = example_path(&self.dir, day, example);
+            return if path.is_file() {
+                Ok(Source::File(path))
+            } else {
+                Err(format!("There's no example {example} for day {day}: {} doesn't exist", path.display()))
+            };
+        }
+
+        let path = puzzle_path(&self.dir, day);
+        if path.is_file() {
+            Ok(Source::File(path))
+        } else if piped {
+            Ok(Source::Stdin)
+        } else {
+            Err(format!("There's no input for day {day}: {} doesn't exist. Save it there, \
+                pass --input FILE or pipe it through the standard input", path.display()))
+        }
+    }
+
+    /// Resolves and reads the input of `day`, falling back to the standard input only if it
+    /// isn't a terminal
+    pub fn load(&self, day: u8) -> Result<String, String> {
+        self.resolve(day, !stdin().is_terminal())?.read()
+    }
+
+    /// Same as [`Request::load`], without reading the input in memory
+    pub fn open(&self, day: u8) -> Result<Box<dyn Read>, String> {
+        self.resolve(day, !stdin().is_terminal())?.open()
+    }
+}
+
+impl Source {
+    pub fn read(&self) -> Result<String, String> {
+        match self {
+            Source::File(path) => fs::read_to_string(path).map_err(|e| format!("Can't read {}: {e}", path.display())),
+            Source::Stdin => read_to_string(stdin()).map_err(|e| format!("Can't read the standard input: {e}")),
+        }
+    }
+
+    /// A reader over the input, for the days that can handle inputs too big for the memory
+    pub fn open(&self) -> Result<Box<dyn Read>, String> {
+        match self {
+            Source::File(path) => Ok(Box::new(fs::File::open(path).map_err(|e| format!("Can't read {}: {e}", path.display()))?)),
+            Source::Stdin => Ok(Box::new(stdin())),
+        }
+    }
+}
+
+/// Reads the input of `day` as requested in the program arguments, or quits with a diagnostic
+pub fn for_day(day: u8) -> String {
+    let request = Request::from_args().unwrap_or_else(|msg| {
+        eprintln!("{msg}");
+        exit(2);
+    });
+
+    request.load(day).unwrap_or_else(|msg| {
+        eprintln!("{msg}");
+        exit(1);
+    })
+}
+
+/// Same as [`for_day`], returning a reader over the input instead of reading it all
+pub fn reader_for_day(day: u8) -> Box<dyn Read> {
+    let request = Request::from_args().unwrap_or_else(|msg| {
+        eprintln!("{msg}");
+        exit(2);
+    });
+
+    request.open(day).unwrap_or_else(|msg| {
+        eprintln!("{msg}");
+        exit(1);
+    })
+}
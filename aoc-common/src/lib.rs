@@ -0,0 +1,17 @@
+pub mod answer;
+pub mod bench;
+pub mod error;
+pub mod generate;
+pub mod grid;
+pub mod input;
+pub mod point;
+pub mod puzzle;
+pub mod render;
+pub mod report;
+pub mod testing;
+
+pub use answer::Answer;
+pub use error::{ParseError, ParseResult};
+pub use grid::Grid;
+pub use point::{Compass, Direction, Point};
+pub use puzzle::Puzzle;
@@ -0,0 +1,153 @@
+use std::cmp::Ordering;
+
+/// The four orthogonal directions, as used by most of the grid walking puzzles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [Direction::Up, Direction::Down, Direction::Left, Direction::Right];
+
+    /// `(dx, dy)`, with `y` growing downwards
+    pub fn delta(&self) -> (i32, i32) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+            Direction::Right => (1, 0),
+        }
+    }
+
+    pub fn turn_right(&self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn turn_left(&self) -> Direction {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Left => Direction::Down,
+            Direction::Down => Direction::Right,
+            Direction::Right => Direction::Up,
+        }
+    }
+
+    pub fn opposite(&self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+}
+
+/// The eight directions of an 8-connected grid, clockwise from north.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Compass {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Compass {
+    pub const ALL: [Compass; 8] = [
+        Compass::N, Compass::NE, Compass::E, Compass::SE,
+        Compass::S, Compass::SW, Compass::W, Compass::NW,
+    ];
+
+    /// `(dx, dy)`, with `y` growing downwards
+    pub fn delta(&self) -> (i32, i32) {
+        match self {
+            Compass::N => (0, -1),
+            Compass::NE => (1, -1),
+            Compass::E => (1, 0),
+            Compass::SE => (1, 1),
+            Compass::S => (0, 1),
+            Compass::SW => (-1, 1),
+            Compass::W => (-1, 0),
+            Compass::NW => (-1, -1),
+        }
+    }
+}
+
+impl From<Direction> for Compass {
+    fn from(value: Direction) -> Self {
+        match value {
+            Direction::Up => Compass::N,
+            Direction::Down => Compass::S,
+            Direction::Left => Compass::W,
+            Direction::Right => Compass::E,
+        }
+    }
+}
+
+/// A position on a grid. `x` is the column and `y` the row, so points sort in reading order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point {
+    pub fn new(x: i32, y: i32) -> Self {
+        Point { x, y }
+    }
+
+    pub fn from_row_col(row: usize, col: usize) -> Self {
+        Point { x: col as i32, y: row as i32 }
+    }
+
+    pub fn offset(&self, dx: i32, dy: i32) -> Point {
+        Point { x: self.x + dx, y: self.y + dy }
+    }
+
+    pub fn step(&self, dir: Direction) -> Point {
+        let (dx, dy) = dir.delta();
+        self.offset(dx, dy)
+    }
+
+    pub fn step_towards(&self, heading: Compass) -> Point {
+        let (dx, dy) = heading.delta();
+        self.offset(dx, dy)
+    }
+
+    /// Orthogonal neighbors, without any bounds checking
+    pub fn neighbors4(&self) -> [Point; 4] {
+        Direction::ALL.map(|dir| self.step(dir))
+    }
+
+    /// Orthogonal and diagonal neighbors, without any bounds checking
+    pub fn neighbors8(&self) -> [Point; 8] {
+        Compass::ALL.map(|heading| self.step_towards(heading))
+    }
+
+    pub fn manhattan(&self, other: &Point) -> usize {
+        ((self.x - other.x).abs() + (self.y - other.y).abs()) as usize
+    }
+}
+
+impl PartialOrd for Point {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Point {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.y.cmp(&other.y).then(self.x.cmp(&other.x))
+    }
+}
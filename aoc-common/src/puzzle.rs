@@ -0,0 +1,30 @@
+use crate::{answer::Answer, error::ParseResult, render::Animation};
+
+/// A day's solution, split into parsing and solving so that every step can be timed on its own
+pub trait Puzzle {
+    type Input;
+
+    fn parse(input: &str) -> ParseResult<Self::Input>;
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(input: &Self::Input) -> Answer;
+
+    /// A visual trace of the solution, for the days that have one
+    fn render(_input: &Self::Input) -> Option<Animation> {
+        None
+    }
+}
+
+/// Parses the input and solves the first part
+pub fn solve_part1<P: Puzzle>(input: &str) -> ParseResult<Answer> {
+    P::parse(input).map(|input| P::part1(&input))
+}
+
+/// Parses the input and solves the second part
+pub fn solve_part2<P: Puzzle>(input: &str) -> ParseResult<Answer> {
+    P::parse(input).map(|input| P::part2(&input))
+}
+
+/// Parses the input and draws the trace of the solution, if the puzzle has one
+pub fn render<P: Puzzle>(input: &str) -> ParseResult<Option<Animation>> {
+    P::parse(input).map(|input| P::render(&input))
+}
@@ -0,0 +1,362 @@
+//! Visual traces of the grid puzzles.
+//!
+//! A [`Canvas`] is built from a puzzle grid, then overlays (visited cells, paths, highlighted
+//! cells) are drawn on top of it. It can be printed to the terminal, with or without colours,
+//! or written as an image. An [`Animation`] is a sequence of canvases, shown one after the
+//! other in the terminal or written as an animated GIF.
+//!
+//! PNG and GIF output need the `images` feature. PPM and text output are always available.
+
+use std::{
+    fs,
+    io::{self, Write},
+    path::Path,
+    thread,
+    time::Duration,
+};
+
+use crate::{grid::Grid, point::{Direction, Point}};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const DARK: Rgb = Rgb(40, 40, 40);
+    pub const GREY: Rgb = Rgb(90, 90, 90);
+    pub const RED: Rgb = Rgb(220, 50, 47);
+    pub const GREEN: Rgb = Rgb(80, 200, 80);
+    pub const BLUE: Rgb = Rgb(60, 120, 230);
+    pub const YELLOW: Rgb = Rgb(240, 200, 40);
+    pub const ORANGE: Rgb = Rgb(240, 130, 30);
+    pub const CYAN: Rgb = Rgb(40, 200, 210);
+    pub const MAGENTA: Rgb = Rgb(210, 70, 200);
+}
+
+/// What is drawn on a cell: a character in the terminal, a block of colour in the images.
+/// The background, when there's one, takes precedence over the colour in the images.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub glyph: char,
+    pub color: Rgb,
+    pub background: Option<Rgb>,
+}
+
+impl Cell {
+    pub const EMPTY: Cell = Cell { glyph: '.', color: Rgb::DARK, background: None };
+
+    pub fn new(glyph: char, color: Rgb) -> Cell {
+        Cell { glyph, color, background: None }
+    }
+
+    fn fill(&self) -> Rgb {
+        self.background.unwrap_or(self.color)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Canvas {
+    cells: Grid<Cell>,
+}
+
+impl Canvas {
+    pub fn new(width: usize, height: usize) -> Canvas {
+        Canvas { cells: Grid::new(width, height, Cell::EMPTY) }
+    }
+
+    /// A canvas with a cell for every cell of the grid, as drawn by `f`
+    pub fn from_grid<T, F>(grid: &Grid<T>, f: F) -> Canvas
+    where
+        F: FnMut(&T) -> Cell,
+    {
+        Canvas { cells: grid.map(f) }
+    }
+
+    pub fn width(&self) -> usize {
+        self.cells.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.height()
+    }
+
+    pub fn get(&self, p: Point) -> Option<&Cell> {
+        self.cells.get(p)
+    }
+
+    /// Draws a single cell. Points out of the canvas are ignored, here and in all the overlays.
+    pub fn draw(&mut self, p: Point, cell: Cell) {
+        self.cells.set(p, cell);
+    }
+
+    /// Draws the same cell on all the points, as for a set of visited cells
+    pub fn mark<'a, I>(&mut self, points: I, cell: Cell)
+    where
+        I: IntoIterator<Item = &'a Point>,
+    {
+        for &p in points {
+            self.draw(p, cell);
+        }
+    }
+
+    /// Changes the background of the points, keeping whatever is drawn on them
+    pub fn highlight<'a, I>(&mut self, points: I, background: Rgb)
+    where
+        I: IntoIterator<Item = &'a Point>,
+    {
+        for &p in points {
+            if let Some(cell) = self.cells.get_mut(p) {
+                cell.background = Some(background);
+            }
+        }
+    }
+
+    /// Draws a path of consecutive orthogonal steps, with an arrow on every cell pointing to
+    /// the next one. The last cell is drawn as `o`, cells not next to the following one as `*`.
+    pub fn path(&mut self, points: &[Point], color: Rgb) {
+        for (i, &p) in points.iter().enumerate() {
+            let towards = points.get(i + 1)
+                .and_then(|next| Direction::ALL.into_iter().find(|&d| p.step(d) == *next));
+            let glyph = match (points.get(i + 1), towards) {
+                (_, Some(direction)) => arrow(direction),
+                (Some(_), None) => '*',
+                (None, _) => 'o',
+            };
+
+            self.draw(p, Cell::new(glyph, color));
+        }
+    }
+
+    fn lines(&self, colors: bool) -> String {
+        let mut text = String::new();
+
+        for row in self.cells.rows() {
+            for cell in row {
+                if colors {
+                    let Rgb(r, g, b) = cell.color;
+                    text.push_str(&format!("\x1b[38;2;{r};{g};{b}m"));
+                    match cell.background {
+                        Some(Rgb(r, g, b)) => text.push_str(&format!("\x1b[48;2;{r};{g};{b}m")),
+                        None => text.push_str("\x1b[49m"),
+                    }
+                }
+                text.push(cell.glyph);
+            }
+
+            if colors {
+                text.push_str("\x1b[0m");
+            }
+            text.push('\n');
+        }
+
+        text
+    }
+
+    /// The glyphs, one line per row
+    pub fn to_plain(&self) -> String {
+        self.lines(false)
+    }
+
+    /// The glyphs with 24-bit ANSI colour codes, one line per row
+    pub fn to_ansi(&self) -> String {
+        self.lines(true)
+    }
+
+    /// RGB bytes of the image, drawing every cell as a square of `scale` pixels
+    fn pixels(&self, scale: usize) -> Vec<u8> {
+        let mut pixels = Vec::with_capacity(self.width() * self.height() * scale * scale * 3);
+
+        for row in self.cells.rows() {
+            for _ in 0..scale {
+                for cell in row {
+                    let Rgb(r, g, b) = cell.fill();
+                    for _ in 0..scale {
+                        pixels.extend([r, g, b]);
+                    }
+                }
+            }
+        }
+
+        pixels
+    }
+
+    /// Writes a binary PPM (`P6`) image
+    pub fn write_ppm<W: Write>(&self, out: &mut W, scale: usize) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width() * scale, self.height() * scale)?;
+        out.write_all(&self.pixels(scale))
+    }
+
+    #[cfg(feature = "images")]
+    pub fn write_png<W: Write>(&self, out: &mut W, scale: usize) -> io::Result<()> {
+        let mut encoder = png::Encoder::new(out, (self.width() * scale) as u32, (self.height() * scale) as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header().map_err(io::Error::other)?;
+        writer.write_image_data(&self.pixels(scale)).map_err(io::Error::other)
+    }
+}
+
+pub fn arrow(direction: Direction) -> char {
+    match direction {
+        Direction::Up => '^',
+        Direction::Down => 'v',
+        Direction::Left => '<',
+        Direction::Right => '>',
+    }
+}
+
+/// A sequence of frames, all of them the same size
+#[derive(Debug, Clone, Default)]
+pub struct Animation {
+    frames: Vec<Canvas>,
+}
+
+impl Animation {
+    pub fn new() -> Animation {
+        Animation::default()
+    }
+
+    pub fn push(&mut self, frame: Canvas) {
+        assert!(
+            self.frames.first().is_none_or(|f| f.width() == frame.width() && f.height() == frame.height()),
+            "All the frames of an animation must have the same size"
+        );
+
+        self.frames.push(frame);
+    }
+
+    pub fn frames(&self) -> &[Canvas] {
+        &self.frames
+    }
+
+    pub fn last(&self) -> Option<&Canvas> {
+        self.frames.last()
+    }
+
+    /// Shows the frames in the terminal, drawing each one over the previous
+    pub fn play<W: Write>(&self, out: &mut W, delay: Duration) -> io::Result<()> {
+        write!(out, "\x1b[2J")?;
+
+        for frame in self.frames.iter() {
+            write!(out, "\x1b[H{}", frame.to_ansi())?;
+            out.flush()?;
+            thread::sleep(delay);
+        }
+
+        Ok(())
+    }
+
+    /// Writes an animated GIF, looping forever. There can't be more than 256 different colours
+    /// in the whole animation.
+    #[cfg(feature = "images")]
+    pub fn write_gif<W: Write>(&self, out: &mut W, scale: usize, delay: Duration) -> io::Result<()> {
+        let Some(first) = self.frames.first() else {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "An animation needs at least one frame"));
+        };
+
+        let mut palette: Vec<Rgb> = vec![];
+        for frame in self.frames.iter() {
+            for (_, cell) in frame.cells.iter() {
+                if !palette.contains(&cell.fill()) {
+                    palette.push(cell.fill());
+                }
+            }
+        }
+        if palette.len() > 256 {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "Too many colours for a GIF"));
+        }
+
+        let width = (first.width() * scale) as u16;
+        let height = (first.height() * scale) as u16;
+        let flat_palette = palette.iter().flat_map(|&Rgb(r, g, b)| [r, g, b]).collect::<Vec<_>>();
+
+        let mut encoder = gif::Encoder::new(out, width, height, &flat_palette).map_err(io::Error::other)?;
+        encoder.set_repeat(gif::Repeat::Infinite).map_err(io::Error::other)?;
+
+        for frame in self.frames.iter() {
+            let indices = frame.pixels(scale)
+                .chunks(3)
+                .map(|c| palette.iter().position(|&p| p == Rgb(c[0], c[1], c[2])).unwrap() as u8)
+                .collect::<Vec<_>>();
+
+            let gif_frame = gif::Frame {
+                width,
+                height,
+                delay: (delay.as_millis() / 10) as u16,
+                buffer: indices.into(),
+                ..gif::Frame::default()
+            };
+            encoder.write_frame(&gif_frame).map_err(io::Error::other)?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the animation to a file, in a format chosen by the extension: `.gif` for the
+    /// whole animation, `.png`, `.ppm` or `.txt` for the last frame only.
+    #[cfg_attr(not(feature = "images"), allow(unused_variables))]
+    pub fn save(&self, path: &Path, scale: usize, delay: Duration) -> Result<(), String> {
+        let Some(last) = self.last() else {
+            return Err("There's nothing to draw".to_string());
+        };
+        let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+        let writable = matches!(extension, "ppm" | "txt")
+            || (cfg!(feature = "images") && matches!(extension, "png" | "gif"));
+        if !writable {
+            return Err(format!("Don't know how to write a '{extension}' file"));
+        }
+
+        let mut out = io::BufWriter::new(fs::File::create(path)
+            .map_err(|e| format!("Can't write {}: {e}", path.display()))?);
+
+        let written = match extension {
+            "ppm" => last.write_ppm(&mut out, scale),
+            "txt" => out.write_all(last.to_plain().as_bytes()),
+            #[cfg(feature = "images")]
+            "png" => last.write_png(&mut out, scale),
+            #[cfg(feature = "images")]
+            "gif" => self.write_gif(&mut out, scale, delay),
+            _ => unreachable!("extension '{extension}' was checked above"),
+        };
+
+        written.and_then(|_| out.flush()).map_err(|e| format!("Can't write {}: {e}", path.display()))
+    }
+}
+
+/// Keeps one of every `every` frames offered to it, so that long traces produce animations of
+/// a reasonable length
+#[derive(Debug, Clone)]
+pub struct Sampler {
+    animation: Animation,
+    every: usize,
+    offered: usize,
+}
+
+impl Sampler {
+    pub fn new(every: usize) -> Sampler {
+        Sampler {
+            animation: Animation::new(),
+            every: every.max(1),
+            offered: 0,
+        }
+    }
+
+    /// Offers a frame, which is only drawn if it's going to be kept
+    pub fn offer<F: FnOnce() -> Canvas>(&mut self, frame: F) {
+        if self.offered.is_multiple_of(self.every) {
+            self.animation.push(frame());
+        }
+        self.offered += 1;
+    }
+
+    /// Keeps a frame no matter what, as the final state of a trace
+    pub fn keep(&mut self, frame: Canvas) {
+        self.animation.push(frame);
+    }
+
+    pub fn finish(self) -> Animation {
+        self.animation
+    }
+}
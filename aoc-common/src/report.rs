@@ -0,0 +1,169 @@
+use std::{
+    fmt::Write as _,
+    io::{self, Write},
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    answer::Answer,
+    error::{or_exit, ParseResult},
+};
+
+/// The answer to one part of a day, along with the time it took to compute it
+#[derive(Debug, Clone)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+impl Record {
+    /// Runs `solver` on the input, timing it
+    pub fn solve<F>(day: u8, part: u8, solver: F, input: &str) -> ParseResult<Record>
+    where
+        F: FnOnce(&str) -> ParseResult<Answer>,
+    {
+        let start = Instant::now();
+        let answer = solver(input)?;
+
+        Ok(Record {
+            day,
+            part,
+            answer,
+            elapsed: start.elapsed(),
+        })
+    }
+
+    fn elapsed_ms(&self) -> f64 {
+        self.elapsed.as_secs_f64() * 1000.0
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => { let _ = write!(out, "\\u{:04x}", c as u32); },
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+
+    out
+}
+
+fn json_answer(answer: &Answer) -> String {
+    match answer {
+        Answer::Number(n) => n.to_string(),
+        Answer::Text(s) => json_string(s),
+        Answer::Unsolved => "null".to_string(),
+    }
+}
+
+// TSV has no escaping, so we just make sure the field separators stay meaningful
+fn tsv_answer(answer: &Answer) -> String {
+    match answer {
+        Answer::Unsolved => String::new(),
+        answer => answer.to_string().replace(['\t', '\n'], " "),
+    }
+}
+
+/// How to print a list of records
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Human,
+    Json,
+    Tsv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "human" => Ok(Format::Human),
+            "json" => Ok(Format::Json),
+            "tsv" => Ok(Format::Tsv),
+            _ => Err(format!("Unknown output format '{s}'. Expected one of: human, json, tsv")),
+        }
+    }
+}
+
+impl Format {
+    /// Looks for `--format <name>` among the program arguments
+    pub fn from_args() -> Result<Option<Format>, String> {
+        let mut args = std::env::args();
+
+        while let Some(arg) = args.next() {
+            if arg == "--format" {
+                return args.next().ok_or("--format needs a value")?.parse().map(Some);
+            }
+        }
+
+        Ok(None)
+    }
+
+    pub fn write<W: Write>(&self, out: &mut W, records: &[Record]) -> io::Result<()> {
+        match self {
+            Format::Human => {
+                for r in records {
+                    writeln!(out, "Day {:02}, part {}: {} ({:.3} ms)", r.day, r.part, r.answer, r.elapsed_ms())?;
+                }
+            }
+            Format::Json => {
+                writeln!(out, "[")?;
+                for (i, r) in records.iter().enumerate() {
+                    let sep = if i + 1 < records.len() { "," } else { "" };
+                    writeln!(out, "  {{\"day\": {}, \"part\": {}, \"answer\": {}, \"elapsed_ms\": {:.3}}}{sep}",
+                        r.day, r.part, json_answer(&r.answer), r.elapsed_ms())?;
+                }
+                writeln!(out, "]")?;
+            }
+            Format::Tsv => {
+                writeln!(out, "day\tpart\tanswer\telapsed_ms")?;
+                for r in records {
+                    writeln!(out, "{}\t{}\t{}\t{:.3}", r.day, r.part, tsv_answer(&r.answer), r.elapsed_ms())?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn print(&self, records: &[Record]) {
+        self.write(&mut io::stdout().lock(), records).expect("Couldn't write to the standard output");
+    }
+}
+
+/// Solves both parts of a day and prints the records in the format requested with `--format`.
+/// Returns `false` if no format was requested, so the caller can print its own messages instead.
+/// Invalid inputs are reported as with [`or_exit`].
+pub fn report_if_requested<F1, F2>(day: u8, input: &str, part1: F1, part2: F2) -> bool
+where
+    F1: FnOnce(&str) -> ParseResult<Answer>,
+    F2: FnOnce(&str) -> ParseResult<Answer>,
+{
+    match Format::from_args() {
+        Ok(Some(format)) => {
+            format.print(&[
+                or_exit(Record::solve(day, 1, part1, input)),
+                or_exit(Record::solve(day, 2, part2, input)),
+            ]);
+            true
+        }
+        Ok(None) => false,
+        Err(msg) => {
+            eprintln!("{msg}");
+            std::process::exit(2);
+        }
+    }
+}
@@ -0,0 +1,83 @@
+//! Regression harness for the puzzle examples.
+//!
+//! Every day keeps its example inputs in a `fixtures` directory, along with an `expected.tsv`
+//! file listing the known answers, one per line:
+//!
+//! ```text
+//! # fixture      part    answer
+//! example1.txt   1       11
+//! example1.txt   2       31
+//! ```
+//!
+//! Fields are separated by tabs. Blank lines and lines starting with `#` are ignored. Parts
+//! without a known answer for a given fixture are simply not listed.
+
+use std::{fs, path::Path};
+
+use crate::{answer::Answer, error::ParseResult};
+
+/// A known answer for one of the fixtures
+#[derive(Debug)]
+pub struct Expectation {
+    pub fixture: String,
+    pub part: u8,
+    pub answer: String,
+}
+
+pub fn read_expectations(fixtures: &Path) -> Vec<Expectation> {
+    let path = fixtures.join("expected.tsv");
+    let content = fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("Can't read {}: {e}", path.display()));
+
+    content.lines()
+        .enumerate()
+        .filter(|(_, l)| !l.trim().is_empty() && !l.starts_with('#'))
+        .map(|(n, l)| {
+            let fields = l.split('\t').collect::<Vec<_>>();
+            assert!(fields.len() == 3, "{}:{}: expected 3 tab-separated fields", path.display(), n + 1);
+
+            Expectation {
+                fixture: fields[0].to_string(),
+                part: fields[1].parse().unwrap_or_else(|_| panic!("{}:{}: bad part number", path.display(), n + 1)),
+                answer: fields[2].to_string(),
+            }
+        })
+        .collect()
+}
+
+/// Runs the parts on every fixture listed in `<crate_dir>/fixtures/expected.tsv`, panicking
+/// with a list of all the mismatches if any answer differs from the expected one. Fixtures
+/// rejected by the parser count as mismatches too.
+pub fn check_fixtures<F1, F2>(crate_dir: &str, part1: F1, part2: F2)
+where
+    F1: Fn(&str) -> ParseResult<Answer>,
+    F2: Fn(&str) -> ParseResult<Answer>,
+{
+    let fixtures = Path::new(crate_dir).join("fixtures");
+    let expectations = read_expectations(&fixtures);
+    let mut failures = vec![];
+
+    assert!(!expectations.is_empty(), "No expectations listed in {}", fixtures.display());
+
+    for exp in expectations.iter() {
+        let path = fixtures.join(&exp.fixture);
+        let input = fs::read_to_string(&path)
+            .unwrap_or_else(|e| panic!("Can't read {}: {e}", path.display()));
+
+        let answer = match exp.part {
+            1 => part1(&input),
+            2 => part2(&input),
+            n => panic!("There's no part {n}"),
+        };
+
+        match answer {
+            Ok(answer) if answer.to_string() != exp.answer => {
+                failures.push(format!("{} part {}: expected {}, got {answer}", exp.fixture, exp.part, exp.answer));
+            }
+            Ok(_) => {}
+            Err(e) => failures.push(format!("{} part {}: invalid input at {e}", exp.fixture, exp.part)),
+        }
+    }
+
+    assert!(failures.is_empty(), "Wrong answers:\n  {}", failures.join("\n  "));
+}
@@ -0,0 +1,57 @@
+use std::{fs, io::BufReader};
+
+use aoc_common::{
+    bench::{measure, read_baseline, write_baseline, write_table, Step},
+    error::{numbered_lines, parse_at},
+    Answer, ParseResult, Puzzle,
+};
+
+struct Sums;
+
+impl Puzzle for Sums {
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        numbered_lines(BufReader::new(input.as_bytes()))
+            .map(|l| l.and_then(|(n, l)| parse_at(n, &l, &l, "a number")))
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        input.iter().sum::<i64>().into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        input.iter().product::<i64>().into()
+    }
+}
+
+#[test]
+fn measures_every_step() {
+    let measurements = measure::<Sums>(3, "1\n2\n3\n", 4).unwrap();
+
+    assert_eq!(measurements.iter().map(|m| m.step).collect::<Vec<_>>(), [Step::Parse, Step::Part1, Step::Part2]);
+    assert!(measurements.iter().all(|m| m.day == 3 && m.stats.runs == 4 && m.stats.min <= m.stats.median));
+}
+
+#[test]
+fn rejects_invalid_inputs() {
+    assert!(measure::<Sums>(3, "1\nx\n", 4).is_err());
+}
+
+#[test]
+fn baselines_round_trip() {
+    let measurements = measure::<Sums>(3, "1\n2\n3\n", 2).unwrap();
+    let path = std::env::temp_dir().join(format!("aoc-baseline-{}.tsv", std::process::id()));
+
+    write_baseline(&mut fs::File::create(&path).unwrap(), &measurements).unwrap();
+    let baseline = read_baseline(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(baseline.len(), 3);
+    assert!(baseline.contains_key(&(3, Step::Part2)));
+
+    let mut table = vec![];
+    write_table(&mut table, &measurements, Some(&baseline)).unwrap();
+    assert_eq!(String::from_utf8(table).unwrap().lines().count(), 4);
+}
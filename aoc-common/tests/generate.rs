@@ -0,0 +1,39 @@
+use aoc_common::generate::Rng;
+
+#[test]
+fn same_seed_same_numbers() {
+    let mut a = Rng::new(42);
+    let mut b = Rng::new(42);
+    let mut c = Rng::new(43);
+
+    let from_a = (0..10).map(|_| a.next_u64()).collect::<Vec<_>>();
+    let from_b = (0..10).map(|_| b.next_u64()).collect::<Vec<_>>();
+    let from_c = (0..10).map(|_| c.next_u64()).collect::<Vec<_>>();
+
+    assert_eq!(from_a, from_b);
+    assert_ne!(from_a, from_c);
+}
+
+#[test]
+fn stays_in_range() {
+    let mut rng = Rng::new(7);
+
+    for _ in 0..1000 {
+        assert!((-3..=3).contains(&rng.between(-3, 3)));
+        assert!(rng.below(5) < 5);
+    }
+    assert!((0..1000).all(|_| !rng.chance(0.0)));
+    assert!((0..1000).all(|_| rng.chance(1.0)));
+}
+
+#[test]
+fn shuffles_without_losing_anything() {
+    let mut rng = Rng::new(1);
+    let mut items = (0..50).collect::<Vec<_>>();
+
+    rng.shuffle(&mut items);
+    assert_ne!(items, (0..50).collect::<Vec<_>>());
+
+    items.sort();
+    assert_eq!(items, (0..50).collect::<Vec<_>>());
+}
@@ -0,0 +1,79 @@
+use std::io::{BufRead, BufReader};
+
+use aoc_common::{Compass, Grid, Point};
+
+fn sample_grid() -> Grid<char> {
+    Grid::parse_chars(BufReader::new("abc\ndef\n".as_bytes())).unwrap()
+}
+
+fn text<'a>(cells: impl Iterator<Item = &'a char>) -> String {
+    cells.collect()
+}
+
+#[test]
+fn stops_parsing_at_a_blank_line() {
+    let mut stream = BufReader::new("12\n34\n\nrest\n".as_bytes());
+    let grid = Grid::parse(&mut stream, |c| c.to_digit(10).ok_or_else(|| "a digit".to_string())).unwrap();
+
+    assert_eq!((grid.width(), grid.height()), (2, 2));
+    assert_eq!(grid[Point { x: 1, y: 1 }], 4);
+    assert_eq!(stream.lines().next().unwrap().unwrap(), "rest");
+}
+
+#[test]
+fn reports_where_parsing_fails() {
+    let err = Grid::parse_chars(BufReader::new("abc\nabcd\n".as_bytes())).unwrap_err();
+    assert_eq!(err.to_string(), "line 2, column 4: expected a row of 3 cells, found '4 cells'");
+
+    let err = Grid::parse(BufReader::new("#.\n.x\n".as_bytes()), |c| match c {
+        '#' | '.' => Ok(c == '#'),
+        _ => Err("'#' or '.'".to_string()),
+    }).unwrap_err();
+    assert_eq!((err.line, err.column), (2, 2));
+}
+
+#[test]
+fn keeps_neighbors_inside() {
+    let grid = sample_grid();
+    let corner = Point { x: 0, y: 0 };
+    let middle = Point { x: 1, y: 0 };
+
+    assert_eq!(grid.neighbors4(corner).count(), 2);
+    assert_eq!(grid.neighbors8(corner).count(), 3);
+    assert_eq!(grid.neighbors4(middle).count(), 3);
+    assert_eq!(grid.neighbors8(middle).count(), 5);
+    assert!(grid.neighbors8(middle).all(|p| grid.contains(p)));
+}
+
+#[test]
+fn casts_rays_to_the_edge() {
+    let grid = sample_grid();
+
+    assert_eq!(text(grid.ray(Point { x: 0, y: 0 }, Compass::E).map(|p| &grid[p])), "abc");
+    assert_eq!(text(grid.ray(Point { x: 2, y: 1 }, Compass::NW).map(|p| &grid[p])), "fb");
+    assert_eq!(grid.ray(Point { x: 5, y: 5 }, Compass::N).count(), 0);
+}
+
+#[test]
+fn reads_rows_columns_and_diagonals() {
+    let grid = sample_grid();
+
+    assert_eq!(grid.row(1), ['d', 'e', 'f']);
+    assert_eq!(text(grid.column(2)), "cf");
+    assert_eq!(grid.columns().map(text).collect::<Vec<_>>(), ["ad", "be", "cf"]);
+    assert_eq!(grid.diagonals().map(text).collect::<Vec<_>>(), ["c", "bf", "ae", "d"]);
+    assert_eq!(grid.anti_diagonals().map(text).collect::<Vec<_>>(), ["a", "bd", "ce", "f"]);
+}
+
+#[test]
+fn copes_with_empty_grids() {
+    let narrow = Grid::<char>::from_rows(vec![vec![], vec![], vec![]]);
+    let flat = Grid::new(3, 0, '.');
+
+    for grid in [narrow, flat] {
+        assert_eq!(grid.points().count(), 0);
+        assert_eq!(grid.columns().flatten().count(), 0);
+        assert_eq!(grid.diagonals().count(), 0);
+        assert_eq!(grid.anti_diagonals().count(), 0);
+    }
+}
@@ -0,0 +1,47 @@
+use std::{fs, path::PathBuf};
+
+use aoc_common::input::{example_path, puzzle_path, Request, Source};
+
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc-inputs-{name}-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn follows_the_naming_convention() {
+    let dir = PathBuf::from("inputs");
+
+    assert_eq!(puzzle_path(&dir, 6), PathBuf::from("inputs/day06.txt"));
+    assert_eq!(example_path(&dir, 12, 3), PathBuf::from("inputs/day12.example3.txt"));
+}
+
+#[test]
+fn prefers_cached_files_over_stdin() {
+    let dir = scratch_dir("cached");
+    fs::write(puzzle_path(&dir, 1), "3 4\n").unwrap();
+    fs::write(example_path(&dir, 1, 2), "1 1\n").unwrap();
+    let request = Request { dir: dir.clone(), ..Request::default() };
+
+    assert_eq!(request.resolve(1, true), Ok(Source::File(puzzle_path(&dir, 1))));
+    assert_eq!(
+        Request { example: Some(2), ..request.clone() }.resolve(1, true),
+        Ok(Source::File(example_path(&dir, 1, 2))),
+    );
+    assert!(Request { example: Some(3), ..request.clone() }.resolve(1, true).is_err());
+    assert_eq!(Request { path: Some("-".into()), ..request }.resolve(1, false), Ok(Source::Stdin));
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn falls_back_to_stdin_only_when_piped() {
+    let dir = scratch_dir("missing");
+    let request = Request { dir: dir.clone(), ..Request::default() };
+
+    assert_eq!(request.resolve(2, true), Ok(Source::Stdin));
+    let err = request.resolve(2, false).unwrap_err();
+    assert!(err.contains("day02.txt"), "{err}");
+
+    fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,29 @@
+use std::io::BufReader;
+
+use aoc_common::{error::parse_at, Grid, ParseError};
+
+#[test]
+fn reports_position_of_bad_numbers() {
+    let line = "12  3x  7";
+    let part = line.split_whitespace().nth(1).unwrap();
+    let err = parse_at::<u32>(4, line, part, "a number").unwrap_err();
+
+    assert_eq!(err, ParseError::new(4, 5, "a number").found("3x"));
+    assert_eq!(err.to_string(), "line 4, column 5: expected a number, found '3x'");
+}
+
+#[test]
+fn rejects_ragged_grids() {
+    let err = Grid::parse_chars(BufReader::new("abc\nab\nabc\n".as_bytes())).unwrap_err();
+
+    assert_eq!((err.line, err.column), (2, 3));
+}
+
+#[test]
+fn rejects_unexpected_cells() {
+    let err = Grid::parse(BufReader::new("12\n3x\n".as_bytes()), |c| {
+        c.to_digit(10).ok_or_else(|| "a digit".to_string())
+    }).unwrap_err();
+
+    assert_eq!(err.to_string(), "line 2, column 2: expected a digit, found 'x'");
+}
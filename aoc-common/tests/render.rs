@@ -0,0 +1,100 @@
+use std::io::BufReader;
+
+use aoc_common::{
+    render::{Animation, Canvas, Cell, Rgb, Sampler},
+    Grid, Point,
+};
+
+fn sample_grid() -> Grid<char> {
+    Grid::parse_chars(BufReader::new("#..\n.#.\n...\n".as_bytes())).unwrap()
+}
+
+fn walls(grid: &Grid<char>) -> Canvas {
+    Canvas::from_grid(grid, |&c| match c {
+        '#' => Cell::new('#', Rgb::WHITE),
+        _ => Cell::EMPTY,
+    })
+}
+
+#[test]
+fn draws_overlays_on_top_of_the_grid() {
+    let mut canvas = walls(&sample_grid());
+    let path = [Point { x: 1, y: 0 }, Point { x: 2, y: 0 }, Point { x: 2, y: 1 }, Point { x: 2, y: 2 }];
+
+    canvas.mark(&[Point { x: 0, y: 2 }, Point { x: 5, y: 5 }], Cell::new('X', Rgb::YELLOW));
+    canvas.path(&path, Rgb::RED);
+
+    assert_eq!(canvas.to_plain(), "#>v\n.#v\nX.o\n");
+}
+
+#[test]
+fn colours_the_terminal_output() {
+    let mut canvas = Canvas::new(2, 1);
+    canvas.draw(Point { x: 1, y: 0 }, Cell::new('@', Rgb::GREEN));
+    canvas.highlight(&[Point { x: 1, y: 0 }], Rgb::BLUE);
+
+    let ansi = canvas.to_ansi();
+
+    assert!(ansi.contains("\x1b[38;2;80;200;80m\x1b[48;2;60;120;230m@"));
+    assert!(ansi.ends_with("\x1b[0m\n"));
+}
+
+#[test]
+fn writes_scaled_ppm_images() {
+    let canvas = walls(&sample_grid());
+    let mut ppm = vec![];
+
+    canvas.write_ppm(&mut ppm, 2).unwrap();
+
+    let header = b"P6\n6 6\n255\n";
+    assert_eq!(&ppm[..header.len()], header);
+
+    let pixels = &ppm[header.len()..];
+    assert_eq!(pixels.len(), 6 * 6 * 3);
+    // The top left wall covers the first two pixels of the first two rows
+    assert_eq!(&pixels[..6], &[255; 6]);
+    assert_eq!(&pixels[18..24], &[255; 6]);
+    assert_eq!(&pixels[6..9], &[40, 40, 40]);
+}
+
+#[test]
+fn samples_long_traces() {
+    let mut sampler = Sampler::new(3);
+
+    for step in 0..7 {
+        sampler.offer(|| {
+            let mut canvas = Canvas::new(7, 1);
+            canvas.draw(Point { x: step, y: 0 }, Cell::new('*', Rgb::RED));
+            canvas
+        });
+    }
+    sampler.keep(Canvas::new(7, 1));
+
+    let animation = sampler.finish();
+    let drawn = animation.frames().iter().map(|f| f.to_plain()).collect::<Vec<_>>();
+
+    assert_eq!(drawn, ["*......\n", "...*...\n", "......*\n", ".......\n"]);
+}
+
+#[test]
+#[should_panic(expected = "same size")]
+fn rejects_frames_of_different_sizes() {
+    let mut animation = Animation::new();
+    animation.push(Canvas::new(2, 2));
+    animation.push(Canvas::new(3, 2));
+}
+
+#[test]
+fn leaves_files_alone_when_the_format_is_unknown() {
+    let mut animation = Animation::new();
+    animation.push(walls(&sample_grid()));
+    let path = std::env::temp_dir().join(format!("aoc-render-{}.bmp", std::process::id()));
+    std::fs::write(&path, "keep me").unwrap();
+
+    let saved = animation.save(&path, 1, std::time::Duration::from_millis(100));
+    let contents = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(saved, Err("Don't know how to write a 'bmp' file".to_string()));
+    assert_eq!(contents, "keep me");
+}
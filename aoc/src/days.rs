@@ -0,0 +1,74 @@
+use aoc_common::{
+    bench::Measurement,
+    generate::{Params, Rng},
+    render::Animation,
+    Answer, ParseResult,
+};
+
+pub type PartFn = fn(&str) -> ParseResult<Answer>;
+
+/// Times the steps of a day: receives the day number, the input and the number of runs
+pub type BenchFn = fn(u8, &str, usize) -> ParseResult<Vec<Measurement>>;
+
+/// Draws the trace of a day's solution, `None` for the days without one
+pub type RenderFn = fn(&str) -> ParseResult<Option<Animation>>;
+
+/// Writes a random input for a day
+pub type GenerateFn = fn(&mut Rng, &Params) -> String;
+
+pub struct Day {
+    pub number: u8,
+    pub part1: PartFn,
+    pub part2: PartFn,
+    pub bench: BenchFn,
+    pub render: RenderFn,
+    pub generate: GenerateFn,
+}
+
+impl Day {
+    pub fn part(&self, part: u8) -> PartFn {
+        match part {
+            1 => self.part1,
+            2 => self.part2,
+            _ => panic!("There's no part {part}"),
+        }
+    }
+}
+
+macro_rules! day {
+    ($number:literal, $krate:ident, $puzzle:ident) => {
+        Day {
+            number: $number,
+            part1: $krate::part1,
+            part2: $krate::part2,
+            bench: aoc_common::bench::measure::<$krate::$puzzle>,
+            render: aoc_common::puzzle::render::<$krate::$puzzle>,
+            generate: $krate::generate::input,
+        }
+    };
+}
+
+pub const DAYS: &[Day] = &[
+    day!(1, day01, Day01),
+    day!(2, day02, Day02),
+    day!(3, day03, Day03),
+    day!(4, day04, Day04),
+    day!(5, day05, Day05),
+    day!(6, day06, Day06),
+    day!(7, day07, Day07),
+    day!(8, day08, Day08),
+    day!(9, day09, Day09),
+    day!(10, day10, Day10),
+    day!(11, day11, Day11),
+    day!(12, day12, Day12),
+    day!(13, day13, Day13),
+    day!(14, day14, Day14),
+    day!(15, day15, Day15),
+    day!(17, day17, Day17),
+    day!(18, day18, Day18),
+    day!(19, day19, Day19),
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.number == number)
+}
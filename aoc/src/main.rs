@@ -0,0 +1,292 @@
+use std::{
+    fs,
+    io,
+    path::PathBuf,
+    process::exit,
+    time::Duration,
+};
+
+use aoc_common::{
+    bench::{read_baseline, write_baseline, write_table},
+    error::or_exit,
+    generate::{Params, Rng},
+    input::{self, puzzle_path},
+    report::{Format, Record},
+};
+
+mod days;
+
+const USAGE: &str = "\
+Usage: aoc run <day> [--part 1|2] [--input FILE | --example K] [--inputs DIR]
+               [--format human|json|tsv]
+       aoc bench [<day>...] [--runs N] [--inputs DIR] [--save FILE] [--baseline FILE]
+       aoc render <day> [--input FILE | --example K] [--inputs DIR] [--output FILE]
+                  [--scale N] [--delay MS]
+       aoc generate <day> [--seed N] [--size N] [--unsolvable]
+
+run     Runs the solution for a day. Without --part both parts are run. The input is read
+        from FILE ('-' for the standard input), from DIR/dayNN.exampleK.txt with --example,
+        or else from DIR/dayNN.txt (DIR is 'inputs' by default). If that file doesn't
+        exist either, whatever is piped through the standard input is used.
+bench   Times parsing and both parts separately, N times each (5 by default), for the
+        given days or all of them. The input of day N is read from DIR/dayNN.txt (DIR is
+        'inputs' by default); days without an input are skipped. --save stores the median
+        times as a baseline, which --baseline compares against.
+render  Draws a visual trace of the solution for a day, reading the input as 'run' does.
+        It's played in the terminal, or written to FILE: a .gif gets the whole animation,
+        a .png, .ppm or .txt only the final state. Every cell takes N×N pixels (4 by
+        default) and every frame is shown for MS milliseconds (50 by default).
+generate
+        Writes a random input for a day to the standard output. The same seed (0 by
+        default) always gives the same input. What the size means depends on the day (the
+        side of a map, the number of lines...); it's 100 by default. The input has a
+        solution unless --unsolvable is given.";
+
+struct RunOptions {
+    day: u8,
+    parts: Vec<u8>,
+    input: input::Request,
+    format: Format,
+}
+
+fn parse_run_args(mut args: impl Iterator<Item = String>) -> Result<RunOptions, String> {
+    let day = args.next().ok_or("Missing the day to run")?;
+    let day = day.parse::<u8>().map_err(|_| format!("Not a valid day: {day}"))?;
+    let mut parts = vec![1, 2];
+    let mut input = input::Request::default();
+    let mut format = Format::Human;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => {
+                let part = args.next().ok_or("--part needs a value")?;
+                match part.as_str() {
+                    "1" => parts = vec![1],
+                    "2" => parts = vec![2],
+                    _ => return Err(format!("Not a valid part: {part}")),
+                }
+            }
+            "--input" => input.path = Some(PathBuf::from(args.next().ok_or("--input needs a value")?)),
+            "--inputs" => input.dir = PathBuf::from(args.next().ok_or("--inputs needs a value")?),
+            "--example" => {
+                let value = args.next().ok_or("--example needs a value")?;
+                input.example = Some(value.parse().map_err(|_| format!("Not a valid example number: {value}"))?);
+            }
+            "--format" => format = args.next().ok_or("--format needs a value")?.parse()?,
+            _ => return Err(format!("Unexpected argument: {arg}")),
+        }
+    }
+
+    Ok(RunOptions { day, parts, input, format })
+}
+
+fn run(options: RunOptions) -> Result<(), String> {
+    let day = days::find(options.day).ok_or(format!("There's no solution for day {}", options.day))?;
+
+    // Not a usage problem, so no need to show the usage
+    let input = options.input.load(day.number).unwrap_or_else(|msg| {
+        eprintln!("{msg}");
+        exit(1);
+    });
+
+    let records = options.parts.iter()
+        .map(|&part| or_exit(Record::solve(day.number, part, day.part(part), &input)))
+        .collect::<Vec<_>>();
+
+    options.format.print(&records);
+
+    Ok(())
+}
+
+struct BenchOptions {
+    days: Vec<u8>,
+    runs: usize,
+    inputs: PathBuf,
+    save: Option<PathBuf>,
+    baseline: Option<PathBuf>,
+}
+
+fn parse_bench_args(mut args: impl Iterator<Item = String>) -> Result<BenchOptions, String> {
+    let mut days = vec![];
+    let mut runs = 5;
+    let mut inputs = PathBuf::from("inputs");
+    let mut save = None;
+    let mut baseline = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--runs" => {
+                let value = args.next().ok_or("--runs needs a value")?;
+                runs = value.parse::<usize>().ok().filter(|&n| n > 0)
+                    .ok_or(format!("Not a valid number of runs: {value}"))?;
+            }
+            "--inputs" => inputs = PathBuf::from(args.next().ok_or("--inputs needs a value")?),
+            "--save" => save = Some(PathBuf::from(args.next().ok_or("--save needs a value")?)),
+            "--baseline" => baseline = Some(PathBuf::from(args.next().ok_or("--baseline needs a value")?)),
+            day => days.push(day.parse::<u8>().map_err(|_| format!("Not a valid day: {day}"))?),
+        }
+    }
+
+    Ok(BenchOptions { days, runs, inputs, save, baseline })
+}
+
+fn bench(options: BenchOptions) -> Result<(), String> {
+    let selected = if options.days.is_empty() {
+        days::DAYS.iter().collect::<Vec<_>>()
+    } else {
+        options.days.iter()
+            .map(|&n| days::find(n).ok_or(format!("There's no solution for day {n}")))
+            .collect::<Result<Vec<_>, _>>()?
+    };
+    let baseline = options.baseline.as_deref().map(read_baseline).transpose()?;
+    let mut measurements = vec![];
+
+    for day in selected {
+        let path = puzzle_path(&options.inputs, day.number);
+        let Ok(input) = fs::read_to_string(&path) else {
+            eprintln!("Skipping day {}: can't read {}", day.number, path.display());
+            continue;
+        };
+
+        measurements.extend(or_exit((day.bench)(day.number, &input, options.runs)));
+    }
+
+    write_table(&mut io::stdout().lock(), &measurements, baseline.as_ref())
+        .expect("Couldn't write to the standard output");
+
+    if let Some(path) = &options.save {
+        fs::File::create(path)
+            .and_then(|mut file| write_baseline(&mut file, &measurements))
+            .map_err(|e| format!("Can't write {}: {e}", path.display()))?;
+    }
+
+    Ok(())
+}
+
+struct RenderOptions {
+    day: u8,
+    input: input::Request,
+    output: Option<PathBuf>,
+    scale: usize,
+    delay: Duration,
+}
+
+fn parse_render_args(mut args: impl Iterator<Item = String>) -> Result<RenderOptions, String> {
+    let day = args.next().ok_or("Missing the day to render")?;
+    let day = day.parse::<u8>().map_err(|_| format!("Not a valid day: {day}"))?;
+    let mut input = input::Request::default();
+    let mut output = None;
+    let mut scale = 4;
+    let mut delay = Duration::from_millis(50);
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => input.path = Some(PathBuf::from(args.next().ok_or("--input needs a value")?)),
+            "--inputs" => input.dir = PathBuf::from(args.next().ok_or("--inputs needs a value")?),
+            "--example" => {
+                let value = args.next().ok_or("--example needs a value")?;
+                input.example = Some(value.parse().map_err(|_| format!("Not a valid example number: {value}"))?);
+            }
+            "--output" => output = Some(PathBuf::from(args.next().ok_or("--output needs a value")?)),
+            "--scale" => {
+                let value = args.next().ok_or("--scale needs a value")?;
+                scale = value.parse::<usize>().ok().filter(|&n| n > 0)
+                    .ok_or(format!("Not a valid scale: {value}"))?;
+            }
+            "--delay" => {
+                let value = args.next().ok_or("--delay needs a value")?;
+                delay = Duration::from_millis(value.parse().map_err(|_| format!("Not a valid delay: {value}"))?);
+            }
+            _ => return Err(format!("Unexpected argument: {arg}")),
+        }
+    }
+
+    Ok(RenderOptions { day, input, output, scale, delay })
+}
+
+fn render(options: RenderOptions) -> Result<(), String> {
+    let day = days::find(options.day).ok_or(format!("There's no solution for day {}", options.day))?;
+
+    let input = options.input.load(day.number).unwrap_or_else(|msg| {
+        eprintln!("{msg}");
+        exit(1);
+    });
+
+    let Some(animation) = or_exit((day.render)(&input)) else {
+        eprintln!("Day {} has no visual trace", day.number);
+        exit(1);
+    };
+
+    let result = match &options.output {
+        Some(path) => animation.save(path, options.scale, options.delay),
+        None => animation.play(&mut io::stdout().lock(), options.delay)
+            .map_err(|e| format!("Couldn't write to the standard output: {e}")),
+    };
+
+    result.unwrap_or_else(|msg| {
+        eprintln!("{msg}");
+        exit(1);
+    });
+
+    Ok(())
+}
+
+struct GenerateOptions {
+    day: u8,
+    seed: u64,
+    params: Params,
+}
+
+fn parse_generate_args(mut args: impl Iterator<Item = String>) -> Result<GenerateOptions, String> {
+    let day = args.next().ok_or("Missing the day to generate an input for")?;
+    let day = day.parse::<u8>().map_err(|_| format!("Not a valid day: {day}"))?;
+    let mut seed = 0;
+    let mut params = Params::default();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seed" => {
+                let value = args.next().ok_or("--seed needs a value")?;
+                seed = value.parse().map_err(|_| format!("Not a valid seed: {value}"))?;
+            }
+            "--size" => {
+                let value = args.next().ok_or("--size needs a value")?;
+                params.size = value.parse().map_err(|_| format!("Not a valid size: {value}"))?;
+            }
+            "--unsolvable" => params.solvable = false,
+            _ => return Err(format!("Unexpected argument: {arg}")),
+        }
+    }
+
+    Ok(GenerateOptions { day, seed, params })
+}
+
+fn generate(options: GenerateOptions) -> Result<(), String> {
+    let day = days::find(options.day).ok_or(format!("There's no solution for day {}", options.day))?;
+
+    print!("{}", (day.generate)(&mut Rng::new(options.seed), &options.params));
+
+    Ok(())
+}
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+
+    let result = match args.next().as_deref() {
+        Some("run") => parse_run_args(args).and_then(run),
+        Some("bench") => parse_bench_args(args).and_then(bench),
+        Some("render") => parse_render_args(args).and_then(render),
+        Some("generate") => parse_generate_args(args).and_then(generate),
+        Some("help") | Some("--help") | Some("-h") => {
+            println!("{USAGE}");
+            Ok(())
+        }
+        Some(cmd) => Err(format!("Unknown command: {cmd}")),
+        None => Err("Missing command".to_string()),
+    };
+
+    if let Err(msg) = result {
+        eprintln!("{msg}\n\n{USAGE}");
+        exit(2);
+    }
+}
@@ -0,0 +1,103 @@
+use std::io::BufRead;
+
+use aoc_common::{
+    error::{numbered_lines, parse_at, ParseError},
+    ParseResult,
+};
+
+use crate::frequency::Frequencies;
+
+fn numbers(n: usize) -> String {
+    match n {
+        1 => "1 number".to_string(),
+        n => format!("{n} numbers"),
+    }
+}
+
+/// A table of whitespace-separated numbers, stored column by column
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Columns {
+    columns: Vec<Vec<i64>>,
+}
+
+impl Columns {
+    /// Reads the table line by line. All the lines must have as many numbers as the first one,
+    /// or exactly `expected` if given.
+    pub fn read<R>(stream: R, expected: Option<usize>) -> ParseResult<Columns>
+        where R: BufRead
+    {
+        let mut columns: Vec<Vec<i64>> = vec![];
+        let mut width = expected;
+
+        for line in numbered_lines(stream) {
+            let (n, line) = line?;
+            let k = line
+                .split_whitespace()
+                .map(|s| parse_at::<i64>(n, &line, s, "a number"))
+                .collect::<ParseResult<Vec<_>>>()?;
+
+            if k.is_empty() {
+                return Err(ParseError::new(n, 1, width.map_or("some numbers".to_string(), numbers)).found(""));
+            }
+
+            let width = *width.get_or_insert(k.len());
+            if k.len() != width {
+                return Err(ParseError::new(n, 1, numbers(width)).found(numbers(k.len())));
+            }
+
+            columns.resize_with(width, Vec::new);
+            for (column, value) in columns.iter_mut().zip(k) {
+                column.push(value);
+            }
+        }
+
+        Ok(Columns { columns })
+    }
+
+    /// Number of columns
+    pub fn width(&self) -> usize {
+        self.columns.len()
+    }
+
+    /// Number of rows
+    pub fn len(&self) -> usize {
+        self.columns.first().map_or(0, |c| c.len())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn column(&self, index: usize) -> Option<&[i64]> {
+        self.columns.get(index).map(|c| c.as_slice())
+    }
+
+    pub fn into_columns(self) -> Vec<Vec<i64>> {
+        self.columns
+    }
+
+    fn sorted(&self, index: usize) -> Option<Vec<i64>> {
+        self.column(index).map(|c| {
+            let mut sorted = c.to_vec();
+            sorted.sort();
+            sorted
+        })
+    }
+
+    /// Sum of the distances between the values of two columns, pairing them smallest to
+    /// smallest. `None` if any of the columns doesn't exist.
+    pub fn distance(&self, a: usize, b: usize) -> Option<i64> {
+        let (a, b) = (self.sorted(a)?, self.sorted(b)?);
+
+        Some(a.iter().zip(b.iter()).map(|(x, y)| (x - y).abs()).sum())
+    }
+
+    /// Sum of every value of column `a` times the number of times it appears in column `b`.
+    /// `None` if any of the columns doesn't exist.
+    pub fn similarity(&self, a: usize, b: usize) -> Option<i64> {
+        let a = self.column(a)?.iter().copied().collect::<Frequencies<_>>();
+        let b = self.column(b)?.iter().copied().collect::<Frequencies<_>>();
+
+        Some(a.similarity(&b))
+    }
+}
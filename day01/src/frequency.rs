@@ -0,0 +1,104 @@
+use std::{
+    collections::HashMap,
+    hash::Hash,
+};
+
+/// How many times every value shows up in a collection. The order of the values doesn't matter.
+#[derive(Debug, Clone)]
+pub struct Frequencies<T> {
+    counts: HashMap<T, usize>,
+}
+
+impl<T: Eq + Hash> PartialEq for Frequencies<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.counts == other.counts
+    }
+}
+
+impl<T: Eq + Hash> Eq for Frequencies<T> {}
+
+impl<T: Eq + Hash> Default for Frequencies<T> {
+    fn default() -> Self {
+        Frequencies { counts: HashMap::new() }
+    }
+}
+
+impl<T: Eq + Hash> FromIterator<T> for Frequencies<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut frequencies = Frequencies::default();
+        frequencies.extend(iter);
+        frequencies
+    }
+}
+
+impl<T: Eq + Hash> Extend<T> for Frequencies<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            *self.counts.entry(value).or_insert(0) += 1;
+        }
+    }
+}
+
+impl<T: Eq + Hash> Frequencies<T> {
+    /// Times `value` shows up, zero if it doesn't
+    pub fn count(&self, value: &T) -> usize {
+        self.counts.get(value).copied().unwrap_or(0)
+    }
+
+    /// Number of values counted, repetitions included
+    pub fn total(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    /// Number of different values
+    pub fn distinct(&self) -> usize {
+        self.counts.len()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&T, usize)> {
+        self.counts.iter().map(|(v, &c)| (v, c))
+    }
+
+    /// The values present in both, as many times as in the one having fewer of them
+    pub fn intersection(&self, other: &Frequencies<T>) -> Frequencies<T>
+    where
+        T: Clone,
+    {
+        let counts = self.counts.iter()
+            .filter_map(|(v, &c)| match c.min(other.count(v)) {
+                0 => None,
+                common => Some((v.clone(), common)),
+            })
+            .collect();
+
+        Frequencies { counts }
+    }
+
+    /// Sum, for every value in both, of its weight times the times it shows up in each
+    pub fn weighted_similarity<F>(&self, other: &Frequencies<T>, weight: F) -> i64
+    where
+        F: Fn(&T) -> i64,
+    {
+        self.counts.iter()
+            .map(|(v, &c)| weight(v) * (c * other.count(v)) as i64)
+            .sum()
+    }
+}
+
+impl<T: Eq + Hash + Ord + Clone> Frequencies<T> {
+    /// The `k` most frequent values with their counts, most frequent first. Ties are broken by
+    /// value, smallest first.
+    pub fn top(&self, k: usize) -> Vec<(T, usize)> {
+        let mut all = self.counts.iter().map(|(v, &c)| (v.clone(), c)).collect::<Vec<_>>();
+        all.sort_by(|(v1, c1), (v2, c2)| c2.cmp(c1).then_with(|| v1.cmp(v2)));
+        all.truncate(k);
+        all
+    }
+}
+
+impl Frequencies<i64> {
+    /// The puzzle's similarity score: every value weighs itself
+    pub fn similarity(&self, other: &Frequencies<i64>) -> i64 {
+        self.weighted_similarity(other, |&v| v)
+    }
+}
@@ -0,0 +1,16 @@
+//! Random location lists. `size` is the number of lines.
+
+use aoc_common::generate::{Params, Rng};
+
+pub fn input(rng: &mut Rng, params: &Params) -> String {
+    let left = (0..params.size).map(|_| rng.between(10000, 99999)).collect::<Vec<_>>();
+    let mut text = String::new();
+
+    for &l in left.iter() {
+        // Some of the right ids repeat left ones, so that there's something to find similar
+        let r = if rng.chance(0.3) { *rng.pick(&left) } else { rng.between(10000, 99999) };
+        text.push_str(&format!("{l}   {r}\n"));
+    }
+
+    text
+}
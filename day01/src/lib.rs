@@ -0,0 +1,67 @@
+pub mod columns;
+pub mod frequency;
+pub mod generate;
+pub mod metrics;
+
+use aoc_common::{puzzle, Answer, ParseResult, Puzzle};
+use columns::Columns;
+use frequency::Frequencies;
+use std::io::{BufRead, BufReader};
+
+fn first_half(l1: &[i64], l2: &[i64]) -> i64 {
+    l1.iter().zip(l2.iter())
+        .map(|(&a, &b)| { (a - b).abs() })
+        .sum::<i64>()
+}
+
+// The lists don't need to be sorted for this one
+fn second_half(l1: &[i64], l2: &[i64]) -> i64 {
+    let left = l1.iter().copied().collect::<Frequencies<_>>();
+    let right = l2.iter().copied().collect::<Frequencies<_>>();
+
+    left.similarity(&right)
+}
+
+/// The two lists of the puzzle, in input order
+pub fn read_lists<R>(stream: R) -> ParseResult<(Vec<i64>, Vec<i64>)>
+    where R: BufRead
+{
+    let mut columns = Columns::read(stream, Some(2))?.into_columns();
+
+    // Empty inputs have no columns at all
+    let l2 = columns.pop().unwrap_or_default();
+    let l1 = columns.pop().unwrap_or_default();
+
+    Ok((l1, l2))
+}
+
+pub struct Day01;
+
+impl Puzzle for Day01 {
+    type Input = (Vec<i64>, Vec<i64>);
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        let (mut l1, mut l2) = read_lists(BufReader::new(input.as_bytes()))?;
+
+        l1.sort();
+        l2.sort();
+
+        Ok((l1, l2))
+    }
+
+    fn part1((l1, l2): &Self::Input) -> Answer {
+        first_half(l1, l2).into()
+    }
+
+    fn part2((l1, l2): &Self::Input) -> Answer {
+        second_half(l1, l2).into()
+    }
+}
+
+pub fn part1(input: &str) -> ParseResult<Answer> {
+    puzzle::solve_part1::<Day01>(input)
+}
+
+pub fn part2(input: &str) -> ParseResult<Answer> {
+    puzzle::solve_part2::<Day01>(input)
+}
@@ -1,51 +1,79 @@
-use itertools::Itertools;
-use std::{
-    collections::HashMap,
-    io::{BufRead, BufReader}
-};
-
-fn first_half(l1: &Vec<i64>, l2: &Vec<i64>) {
-    let sum_diffs= l1.iter().zip(l2.iter())
-                        .map(|(&a, &b)| { (a - b).abs() })
-                        .sum::<i64>();
-    println!("Sum of distances: {sum_diffs}");
+use std::process::exit;
+
+use aoc_common::{error::or_exit, input::for_day, report::report_if_requested};
+use day01::{columns::Columns, metrics::Metric, read_lists};
+
+fn arg_value(name: &str) -> Option<String> {
+    std::env::args().skip_while(|a| a != name).nth(1)
 }
 
-fn second_half(l1: &Vec<i64>, l2: &Vec<i64>) {
-    let mapping = l2.into_iter().dedup_with_count().map(|(a, &b)| (b, a)).collect::<HashMap<_, _>>();
-    let score = l1.into_iter()
-                        .map(|val| mapping.get(val).map_or(0, |&times| (*val as usize) * times))
-                        .sum::<usize>();
+/// The columns asked for with `--columns A,B` (counting from 1), if any
+fn chosen_columns() -> Option<(usize, usize)> {
+    let value = arg_value("--columns")?;
 
+    let chosen = value.split_once(',')
+        .and_then(|(a, b)| Some((a.trim().parse::<usize>().ok()?, b.trim().parse::<usize>().ok()?)))
+        .filter(|&(a, b)| a > 0 && b > 0);
+
+    if chosen.is_none() {
+        eprintln!("Expected two column numbers like '1,3' after --columns, not '{value}'");
+        exit(2);
+    }
 
-    println!("Similarity score: {score}");
+    chosen
 }
 
-fn read_lists<R>(stream: R) -> (Vec<i64>, Vec<i64>)
-    where R: BufRead
-{
-    let mut l1 = vec![];
-    let mut l2 = vec![];
-
-    for line in stream.lines() {
-        let k = line.unwrap().trim()
-            .split_whitespace()
-            .map(|s| s.parse::<i64>().unwrap())
-            .collect::<Vec<_>>();
-        l1.push(k[0]);
-        l2.push(k[1]);
+/// The metrics asked for with `--metric NAME`, where NAME can be `all`
+fn chosen_metrics() -> Option<Vec<Metric>> {
+    let value = arg_value("--metric")?;
+
+    if value == "all" {
+        return Some(Metric::ALL.to_vec());
     }
 
-    (l1, l2)
+    match value.parse() {
+        Ok(metric) => Some(vec![metric]),
+        Err(msg) => {
+            eprintln!("{msg}");
+            exit(2);
+        }
+    }
 }
 
 fn main() {
-    let stream = BufReader::new(std::io::stdin());
-    let (mut l1, mut l2) = read_lists(stream);
+    let chosen = chosen_columns();
+    let metrics = chosen_metrics();
+    let input = for_day(1);
+
+    if let Some(metrics) = metrics {
+        let (l1, l2) = or_exit(read_lists(input.as_bytes()));
 
-    l1.sort();
-    l2.sort();
+        for metric in metrics {
+            match metric.measure(&l1, &l2) {
+                Some(value) => println!("{}: {value}", metric.description()),
+                None => println!("{}: undefined", metric.description()),
+            }
+        }
+        return;
+    }
+
+    if let Some((a, b)) = chosen {
+        let table = or_exit(Columns::read(input.as_bytes(), None));
+
+        let (Some(distance), Some(similarity)) = (table.distance(a - 1, b - 1), table.similarity(a - 1, b - 1)) else {
+            eprintln!("The input has {} columns only", table.width());
+            exit(1);
+        };
+
+        println!("Sum of distances between columns {a} and {b}: {distance}");
+        println!("Similarity score of column {a} in column {b}: {similarity}");
+        return;
+    }
+
+    if report_if_requested(1, &input, day01::part1, day01::part2) {
+        return;
+    }
 
-    first_half(&l1, &l2);
-    second_half(&l1, &l2);
+    println!("Sum of distances: {}", or_exit(day01::part1(&input)));
+    println!("Similarity score: {}", or_exit(day01::part2(&input)));
 }
@@ -0,0 +1,144 @@
+//! Ways of comparing the two lists, besides the puzzle's sum of distances.
+//!
+//! The distances (L1, L2, maximum deviation and earth mover's) pair the values smallest to
+//! smallest, as the puzzle does. The rank correlations (Kendall and Spearman) pair them as they
+//! come in the input instead: sorted, both lists would always be perfectly correlated.
+
+use std::{fmt::Display, str::FromStr};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Metric {
+    L1,
+    L2,
+    MaxDeviation,
+    EarthMover,
+    Kendall,
+    Spearman,
+}
+
+impl Metric {
+    pub const ALL: [Metric; 6] = [
+        Metric::L1, Metric::L2, Metric::MaxDeviation, Metric::EarthMover, Metric::Kendall, Metric::Spearman,
+    ];
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Metric::L1 => "Sum of distances",
+            Metric::L2 => "Euclidean distance",
+            Metric::MaxDeviation => "Maximum deviation",
+            Metric::EarthMover => "Earth mover's distance",
+            Metric::Kendall => "Kendall rank correlation",
+            Metric::Spearman => "Spearman rank correlation",
+        }
+    }
+
+    /// Compares two lists of the same length, in input order. `None` when the metric isn't
+    /// defined for them, as the correlations of lists with less than two different values.
+    pub fn measure(&self, l1: &[i64], l2: &[i64]) -> Option<f64> {
+        assert_eq!(l1.len(), l2.len(), "The lists must have the same length");
+
+        match self {
+            Metric::L1 => Some(sorted_pairs(l1, l2).map(|(a, b)| (a - b).abs() as f64).sum()),
+            Metric::L2 => Some(sorted_pairs(l1, l2).map(|(a, b)| ((a - b) as f64).powi(2)).sum::<f64>().sqrt()),
+            Metric::MaxDeviation => Some(sorted_pairs(l1, l2).map(|(a, b)| (a - b).abs()).max().unwrap_or(0) as f64),
+            // With the same number of values on both sides, moving every value to its sorted
+            // counterpart is the cheapest way of turning one distribution into the other
+            Metric::EarthMover => match l1.len() {
+                0 => Some(0.0),
+                n => Metric::L1.measure(l1, l2).map(|total| total / n as f64),
+            },
+            Metric::Kendall => kendall(l1, l2),
+            Metric::Spearman => pearson(&ranks(l1), &ranks(l2)),
+        }
+    }
+}
+
+impl Display for Metric {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Metric::L1 => "l1",
+            Metric::L2 => "l2",
+            Metric::MaxDeviation => "max",
+            Metric::EarthMover => "emd",
+            Metric::Kendall => "kendall",
+            Metric::Spearman => "spearman",
+        };
+
+        write!(f, "{name}")
+    }
+}
+
+impl FromStr for Metric {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Metric::ALL.into_iter()
+            .find(|m| m.to_string() == s)
+            .ok_or_else(|| format!("Unknown metric '{s}'. Expected one of: l1, l2, max, emd, kendall, spearman"))
+    }
+}
+
+fn sorted_pairs(l1: &[i64], l2: &[i64]) -> impl Iterator<Item = (i64, i64)> {
+    let mut l1 = l1.to_vec();
+    let mut l2 = l2.to_vec();
+    l1.sort();
+    l2.sort();
+
+    l1.into_iter().zip(l2)
+}
+
+// Tau-b, which accounts for ties
+fn kendall(l1: &[i64], l2: &[i64]) -> Option<f64> {
+    let (mut concordant, mut discordant, mut ties1, mut ties2) = (0i64, 0i64, 0i64, 0i64);
+
+    for i in 0..l1.len() {
+        for j in i + 1..l1.len() {
+            let d1 = (l1[i] - l1[j]).signum();
+            let d2 = (l2[i] - l2[j]).signum();
+
+            match (d1, d2) {
+                (0, 0) => {}
+                (0, _) => ties1 += 1,
+                (_, 0) => ties2 += 1,
+                _ if d1 == d2 => concordant += 1,
+                _ => discordant += 1,
+            }
+        }
+    }
+
+    let denominator = (((concordant + discordant + ties1) * (concordant + discordant + ties2)) as f64).sqrt();
+
+    (denominator > 0.0).then(|| (concordant - discordant) as f64 / denominator)
+}
+
+// Ranks starting at 1, tied values getting the average of their ranks
+fn ranks(values: &[i64]) -> Vec<f64> {
+    let mut order = (0..values.len()).collect::<Vec<_>>();
+    order.sort_by_key(|&i| values[i]);
+
+    let mut ranks = vec![0.0; values.len()];
+    let mut start = 0;
+
+    while start < order.len() {
+        let end = start + order[start..].iter().take_while(|&&i| values[i] == values[order[start]]).count();
+        let rank = (start + end + 1) as f64 / 2.0;
+
+        for &i in &order[start..end] {
+            ranks[i] = rank;
+        }
+        start = end;
+    }
+
+    ranks
+}
+
+fn pearson(x: &[f64], y: &[f64]) -> Option<f64> {
+    let n = x.len() as f64;
+    let (mean_x, mean_y) = (x.iter().sum::<f64>() / n, y.iter().sum::<f64>() / n);
+
+    let covariance = x.iter().zip(y).map(|(a, b)| (a - mean_x) * (b - mean_y)).sum::<f64>();
+    let spread_x = x.iter().map(|a| (a - mean_x).powi(2)).sum::<f64>().sqrt();
+    let spread_y = y.iter().map(|b| (b - mean_y).powi(2)).sum::<f64>().sqrt();
+
+    (spread_x > 0.0 && spread_y > 0.0).then(|| covariance / (spread_x * spread_y))
+}
@@ -0,0 +1,40 @@
+use day01::columns::Columns;
+
+const TABLE: &str = "\
+3   4   3
+4   3   9
+2   5   3
+";
+
+#[test]
+fn reads_any_number_of_columns() {
+    let table = Columns::read(TABLE.as_bytes(), None).unwrap();
+
+    assert_eq!(table.width(), 3);
+    assert_eq!(table.len(), 3);
+    assert_eq!(table.column(2), Some(&[3, 9, 3][..]));
+    assert_eq!(table.column(3), None);
+}
+
+#[test]
+fn compares_any_two_columns() {
+    let table = Columns::read(TABLE.as_bytes(), None).unwrap();
+
+    assert_eq!(table.distance(0, 1), Some(3));
+    assert_eq!(table.distance(0, 2), Some(6));
+    assert_eq!(table.similarity(0, 2), Some(6));
+    assert_eq!(table.similarity(2, 0), Some(6));
+    assert_eq!(table.similarity(0, 3), None);
+}
+
+#[test]
+fn reports_ragged_lines() {
+    let err = Columns::read("1 2 3\n4 5\n".as_bytes(), None).unwrap_err();
+    assert_eq!(err.to_string(), "line 2, column 1: expected 3 numbers, found '2 numbers'");
+
+    let err = Columns::read("1 2 3\n".as_bytes(), Some(2)).unwrap_err();
+    assert_eq!(err.to_string(), "line 1, column 1: expected 2 numbers, found '3 numbers'");
+
+    let err = Columns::read("1\n\n".as_bytes(), None).unwrap_err();
+    assert_eq!(err.to_string(), "line 2, column 1: expected 1 number, found nothing");
+}
@@ -0,0 +1,36 @@
+use day01::frequency::Frequencies;
+
+#[test]
+fn counts_values_in_any_order() {
+    let sorted = [3, 3, 3, 4, 5, 9].into_iter().collect::<Frequencies<i64>>();
+    let shuffled = [3, 9, 4, 3, 5, 3].into_iter().collect::<Frequencies<i64>>();
+
+    assert_eq!(sorted, shuffled);
+    assert_eq!(shuffled.count(&3), 3);
+    assert_eq!(shuffled.count(&7), 0);
+    assert_eq!(shuffled.total(), 6);
+    assert_eq!(shuffled.distinct(), 4);
+}
+
+#[test]
+fn similarity_doesnt_need_sorted_lists() {
+    let left = [3, 4, 2, 1, 3, 3].into_iter().collect::<Frequencies<i64>>();
+    let right = [4, 3, 5, 3, 9, 3].into_iter().collect::<Frequencies<i64>>();
+
+    assert_eq!(left.similarity(&right), 31);
+    assert_eq!(left.weighted_similarity(&right, |_| 1), 3 * 3 + 1);
+}
+
+#[test]
+fn top_and_intersection() {
+    let words = "a b a c b a d".split(' ').collect::<Frequencies<_>>();
+    let others = "a a b b b e".split(' ').collect::<Frequencies<_>>();
+
+    assert_eq!(words.top(2), [("a", 3), ("b", 2)]);
+    assert_eq!(words.top(10).len(), 4);
+
+    let common = words.intersection(&others);
+    assert_eq!(common.count(&"a"), 2);
+    assert_eq!(common.count(&"b"), 2);
+    assert_eq!(common.distinct(), 2);
+}
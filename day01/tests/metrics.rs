@@ -0,0 +1,47 @@
+use day01::metrics::Metric;
+
+const L1: [i64; 6] = [3, 4, 2, 1, 3, 3];
+const L2: [i64; 6] = [4, 3, 5, 3, 9, 3];
+
+fn close_to(value: Option<f64>, expected: f64) -> bool {
+    value.is_some_and(|v| (v - expected).abs() < 1e-9)
+}
+
+#[test]
+fn distances_pair_sorted_values() {
+    assert_eq!(Metric::L1.measure(&L1, &L2), Some(11.0));
+    assert_eq!(Metric::L2.measure(&L1, &L2), Some(35f64.sqrt()));
+    assert_eq!(Metric::MaxDeviation.measure(&L1, &L2), Some(5.0));
+    assert_eq!(Metric::EarthMover.measure(&L1, &L2), Some(11.0 / 6.0));
+}
+
+#[test]
+fn correlations_pair_values_in_input_order() {
+    let up = [1, 2, 3, 4, 5];
+    let down = [50, 40, 30, 20, 10];
+    let bumpy = [1, 3, 2, 5, 4];
+
+    assert_eq!(Metric::Kendall.measure(&up, &up), Some(1.0));
+    assert_eq!(Metric::Kendall.measure(&up, &down), Some(-1.0));
+    assert!(close_to(Metric::Kendall.measure(&up, &bumpy), 0.6));
+    assert!(close_to(Metric::Spearman.measure(&up, &down), -1.0));
+    assert!(close_to(Metric::Spearman.measure(&up, &bumpy), 0.8));
+
+    // Ties get the same rank: this is still a perfect match
+    assert!(close_to(Metric::Spearman.measure(&[1, 1, 2], &[5, 5, 7]), 1.0));
+}
+
+#[test]
+fn correlations_of_constant_lists_are_undefined() {
+    assert_eq!(Metric::Kendall.measure(&[1, 1, 1], &[1, 2, 3]), None);
+    assert_eq!(Metric::Spearman.measure(&[], &[]), None);
+}
+
+#[test]
+fn metrics_by_name() {
+    for metric in Metric::ALL {
+        assert_eq!(metric.to_string().parse::<Metric>(), Ok(metric));
+    }
+
+    assert!("cosine".parse::<Metric>().is_err());
+}
@@ -0,0 +1,24 @@
+//! Random reports, most of them safe or nearly safe. `size` is the number of reports.
+
+use aoc_common::generate::{Params, Rng};
+
+pub fn input(rng: &mut Rng, params: &Params) -> String {
+    let mut text = String::new();
+
+    for _ in 0..params.size {
+        let levels = rng.between(5, 8) as usize;
+        let direction = if rng.chance(0.5) { 1 } else { -1 };
+        let mut report = vec![rng.between(10, 90)];
+
+        for _ in 1..levels {
+            let step = if rng.chance(0.1) { rng.between(-4, 5) } else { direction * rng.between(1, 3) };
+            report.push(report.last().unwrap() + step);
+        }
+
+        let report = report.iter().map(|l| l.to_string()).collect::<Vec<_>>();
+        text.push_str(&report.join(" "));
+        text.push('\n');
+    }
+
+    text
+}
@@ -0,0 +1,165 @@
+pub mod generate;
+pub mod safety;
+
+use std::io::{BufReader, Read};
+use aoc_common::{
+    error::{numbered_lines, parse_at, ParseError},
+    puzzle, Answer, ParseResult, Puzzle,
+};
+use safety::{Fault, Reason, SafetyPolicy, Trend};
+
+#[derive(Debug)]
+pub struct Report {
+    levels: Vec<i64>,
+}
+
+impl Report {
+    fn parse(line_no: usize, line: &str) -> ParseResult<Report> {
+        let levels = line.split_whitespace()
+            .map(|n| parse_at::<i64>(line_no, line, n, "a level"))
+            .collect::<ParseResult<Vec<_>>>()?;
+
+        if levels.is_empty() {
+            return Err(ParseError::new(line_no, 1, "at least one level").found(""));
+        }
+
+        Ok(Report { levels })
+    }
+
+    pub fn levels(&self) -> &[i64] {
+        &self.levels
+    }
+
+    /// Finds the fewest levels to remove, at most `tolerance` of them, so that the rest of
+    /// the report is safe. Returns their positions (empty if the report is safe as it is), or
+    /// `None` if it takes more removals than tolerated.
+    pub fn dampen(&self, tolerance: usize) -> Option<Vec<usize>> {
+        self.removals(&SafetyPolicy { tolerated_faults: tolerance, ..SafetyPolicy::STRICT })
+    }
+
+    /// Same as [`Report::dampen`], with the rules and the tolerance of `policy`
+    pub fn removals(&self, policy: &SafetyPolicy) -> Option<Vec<usize>> {
+        policy.trends().into_iter()
+            .filter_map(|trend| self.removals_towards(policy, trend))
+            .min_by_key(|removed| removed.len())
+    }
+
+    /// The first pair of levels breaking the rules of `policy`, or `None` if the report is
+    /// safe under that policy (tolerated faults included)
+    pub fn diagnose(&self, policy: &SafetyPolicy) -> Option<Fault> {
+        if self.removals(policy).is_some() {
+            return None;
+        }
+
+        // With no imposed direction, the first change of level sets it
+        let trend = match policy.trend {
+            Trend::Either => match self.levels.windows(2).map(|w| w[1] - w[0]).find(|&step| step != 0) {
+                Some(step) if step > 0 => Trend::Increasing,
+                Some(_) => Trend::Decreasing,
+                None => Trend::Either,
+            },
+            trend => trend,
+        };
+
+        self.levels.windows(2)
+            .enumerate()
+            .find_map(|(position, w)| {
+                let step = w[1] - w[0];
+                let reason = match trend {
+                    Trend::Increasing if step < 0 => Reason::WrongDirection { expected: policy.trend },
+                    Trend::Decreasing if step > 0 => Reason::WrongDirection { expected: policy.trend },
+                    _ if step.abs() < policy.min_step => Reason::TooSmall { step: step.abs(), min: policy.min_step },
+                    _ if step.abs() > policy.max_step => Reason::TooLarge { step: step.abs(), max: policy.max_step },
+                    _ => return None,
+                };
+
+                Some(Fault { position, levels: (w[0], w[1]), reason })
+            })
+    }
+
+    // Walks the levels once, keeping track of the ways of reaching every level with r removals
+    // so far, for every r up to the tolerance. Linear for a fixed tolerance.
+    fn removals_towards(&self, policy: &SafetyPolicy, trend: Trend) -> Option<Vec<usize>> {
+        let tolerance = policy.tolerated_faults;
+        let levels = &self.levels;
+        let n = levels.len();
+        let fits = |a: i64, b: i64| policy.fits(a, b, trend);
+
+        // reached[i][r]: level i can be kept after removing r of the previous ones. Holds the
+        // previous kept level, if any.
+        let mut reached: Vec<Vec<Option<Option<usize>>>> = vec![vec![None; tolerance + 1]; n];
+
+        for i in 0..n {
+            if i <= tolerance {
+                reached[i][i] = Some(None);
+            }
+
+            for skipped in 0..i.min(tolerance + 1) {
+                let j = i - 1 - skipped;
+                if !fits(levels[j], levels[i]) {
+                    continue;
+                }
+
+                for r in skipped..=tolerance {
+                    if reached[j][r - skipped].is_some() && reached[i][r].is_none() {
+                        reached[i][r] = Some(Some(j));
+                    }
+                }
+            }
+        }
+
+        // The cheapest way of keeping some level as the last one
+        let (mut last, mut r) = (0..n)
+            .flat_map(|i| (0..=tolerance).map(move |r| (i, r)))
+            .filter(|&(i, r)| reached[i][r].is_some() && r + (n - 1 - i) <= tolerance)
+            .min_by_key(|&(i, r)| r + (n - 1 - i))?;
+
+        let mut kept = vec![false; n];
+        kept[last] = true;
+        while let Some(Some(previous)) = reached[last][r] {
+            r -= last - previous - 1;
+            last = previous;
+            kept[last] = true;
+        }
+
+        Some((0..n).filter(|&i| !kept[i]).collect())
+    }
+
+    fn is_safe(&self, dampener: bool) -> bool {
+        self.removals(if dampener { &SafetyPolicy::DAMPENED } else { &SafetyPolicy::STRICT }).is_some()
+    }
+}
+
+fn read_reports<R>(stream: BufReader<R>) -> ParseResult<Vec<Report>>
+    where R: Read
+{
+    numbered_lines(stream)
+        .map(|l| l.and_then(|(n, l)| Report::parse(n, &l)))
+        .collect()
+}
+
+pub struct Day02;
+
+impl Puzzle for Day02 {
+    type Input = Vec<Report>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        read_reports(BufReader::new(input.as_bytes()))
+    }
+
+    fn part1(reports: &Self::Input) -> Answer {
+        reports.iter().filter(|&r| r.is_safe(false)).count().into()
+    }
+
+    fn part2(reports: &Self::Input) -> Answer {
+        reports.iter().filter(|&r| r.is_safe(true)).count().into()
+    }
+}
+
+pub fn part1(input: &str) -> ParseResult<Answer> {
+    puzzle::solve_part1::<Day02>(input)
+}
+
+pub fn part2(input: &str) -> ParseResult<Answer> {
+    puzzle::solve_part2::<Day02>(input)
+}
@@ -1,74 +1,90 @@
-use std::io::{stdin, BufRead, BufReader, Read};
+use std::{io::stdout, process::exit, str::FromStr};
 
-#[derive(Debug)]
-struct Report {
-    levels: Vec<i64>,
-}
+use aoc_common::{error::or_exit, input::for_day, report::report_if_requested, Puzzle};
+use day02::{safety::{self, SafetyPolicy}, Day02};
+
+/// The value following `flag` among the program arguments, if any
+fn arg_value<T: FromStr>(flag: &str, what: &str) -> Option<T> {
+    let value = std::env::args().skip_while(|a| a != flag).nth(1)?;
 
-impl Report {
-    fn from_str(string: &str) -> Report {
-       Report {
-            levels: string.trim().split_whitespace().map(|n| n.parse::<i64>().unwrap()).collect()
+    match value.parse() {
+        Ok(v) => Some(v),
+        Err(_) => {
+            eprintln!("Not a valid {what}: {value}");
+            exit(2);
         }
     }
+}
 
-    fn all_increasing(&self) -> bool {
-        let n = self.levels.len();
+/// The puzzle rules, changed by `--min-step N`, `--max-step N`, `--direction D` and
+/// `--tolerance K`. Returns `None` if none of them is given.
+fn policy() -> Option<SafetyPolicy> {
+    let min_step = arg_value("--min-step", "step");
+    let max_step = arg_value("--max-step", "step");
+    let trend = arg_value("--direction", "direction (increasing, decreasing or either)");
+    let tolerance = arg_value("--tolerance", "tolerance");
 
-        for (&a, &b) in self.levels[0..(n-1)].iter().zip(self.levels[1..].iter()) {
-            let diff = b - a;
-            if diff < 1 || diff > 3 {
-                return false;
-            }
-        }
-
-        true
+    if min_step.is_none() && max_step.is_none() && trend.is_none() && tolerance.is_none() {
+        return None;
     }
 
-    fn all_decreasing(&self) -> bool {
-        let n = self.levels.len();
+    let default = SafetyPolicy::default();
+    let policy = SafetyPolicy {
+        min_step: min_step.unwrap_or(default.min_step),
+        max_step: max_step.unwrap_or(default.max_step),
+        trend: trend.unwrap_or(default.trend),
+        tolerated_faults: tolerance.unwrap_or(default.tolerated_faults),
+    };
+
+    if policy.min_step > policy.max_step {
+        eprintln!("The smallest step ({}) can't be larger than the largest one ({})", policy.min_step, policy.max_step);
+        exit(2);
+    }
 
-        for (&a, &b) in self.levels[0..(n-1)].iter().zip(self.levels[1..].iter()) {
-            let diff = a - b;
-            if diff < 1 || diff > 3 {
-                return false;
-            }
-        }
+    Some(policy)
+}
 
-        true
+fn main() {
+    let policy = policy();
+    let diagnostics = arg_value::<safety::Format>("--diagnose", "diagnostics format (table or json)");
+    let input = for_day(2);
+
+    if let Some(format) = diagnostics {
+        let reports = or_exit(Day02::parse(&input));
+        let policy = policy.unwrap_or_default();
+        let faults = reports.iter()
+            .enumerate()
+            .filter_map(|(n, report)| Some((n + 1, report.diagnose(&policy)?)))
+            .collect::<Vec<_>>();
+
+        safety::write_faults(&mut stdout().lock(), &faults, format).expect("Can't write the diagnostics");
+        return;
     }
 
-    fn is_safe_with_dampener(&self) -> bool {
-        for k in 0..(self.levels.len()) {
-            let mut levels = self.levels.clone();
-            levels.remove(k);
-            let r = Report { levels };
+    if let Some(policy) = policy {
+        let reports = or_exit(Day02::parse(&input));
+        let mut safe = 0;
+
+        for (n, report) in reports.iter().enumerate() {
+            let Some(removed) = report.removals(&policy) else { continue };
+            safe += 1;
 
-            if r.all_increasing() || r.all_decreasing() {
-                return true
+            if !removed.is_empty() {
+                let levels = removed.iter()
+                    .map(|&i| format!("{} (#{})", report.levels()[i], i + 1))
+                    .collect::<Vec<_>>();
+                println!("Report {}: removed {}", n + 1, levels.join(", "));
             }
         }
 
-        false
+        println!("# safe reports (removing up to {} levels): {safe}", policy.tolerated_faults);
+        return;
     }
 
-    fn is_safe(&self) -> bool {
-        self.all_increasing() || self.all_decreasing() || self.is_safe_with_dampener()
+    if report_if_requested(2, &input, day02::part1, day02::part2) {
+        return;
     }
-}
-
-fn read_reports<R>(stream: BufReader<R>) -> Vec<Report>
-    where R: Read
-{
-    stream.lines()
-        .map(|l| Report::from_str(&l.unwrap()))
-        .collect()
-}
-
-fn main() {
-    let reports = read_reports(BufReader::new(stdin()));
-
-    let number_safe = reports.iter().filter(|&r| r.is_safe()).count();
 
-    println!("# safe reports: {number_safe}");
+    println!("# safe reports: {}", or_exit(day02::part1(&input)));
+    println!("# safe reports (with dampener): {}", or_exit(day02::part2(&input)));
 }
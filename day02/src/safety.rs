@@ -0,0 +1,147 @@
+//! What makes a report safe, and why it isn't.
+
+use std::{
+    fmt::Display,
+    io::{self, Write},
+    str::FromStr,
+};
+
+/// Which way the levels may change
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trend {
+    Increasing,
+    Decreasing,
+    /// Either way, as long as it's the same all along the report
+    Either,
+}
+
+impl FromStr for Trend {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "increasing" => Ok(Trend::Increasing),
+            "decreasing" => Ok(Trend::Decreasing),
+            "either" => Ok(Trend::Either),
+            _ => Err(format!("Unknown direction '{s}'. Expected one of: increasing, decreasing, either")),
+        }
+    }
+}
+
+/// The rules a report must follow to be safe
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SafetyPolicy {
+    /// Smallest change between adjacent levels
+    pub min_step: i64,
+    /// Largest change between adjacent levels
+    pub max_step: i64,
+    pub trend: Trend,
+    /// Number of levels that can be removed to make a report safe
+    pub tolerated_faults: usize,
+}
+
+impl SafetyPolicy {
+    /// The rules of the first part of the puzzle
+    pub const STRICT: SafetyPolicy = SafetyPolicy { min_step: 1, max_step: 3, trend: Trend::Either, tolerated_faults: 0 };
+    /// The rules of the second part, with the Problem Dampener
+    pub const DAMPENED: SafetyPolicy = SafetyPolicy { tolerated_faults: 1, ..SafetyPolicy::STRICT };
+
+    /// Whether going from `a` to `b` is a valid step in the given direction, which must not
+    /// be `Either`
+    pub(crate) fn fits(&self, a: i64, b: i64, trend: Trend) -> bool {
+        let step = match trend {
+            Trend::Increasing => b - a,
+            _ => a - b,
+        };
+
+        (self.min_step..=self.max_step).contains(&step)
+    }
+
+    /// The directions a report may follow
+    pub(crate) fn trends(&self) -> Vec<Trend> {
+        match self.trend {
+            Trend::Either => vec![Trend::Increasing, Trend::Decreasing],
+            trend => vec![trend],
+        }
+    }
+}
+
+impl Default for SafetyPolicy {
+    fn default() -> Self {
+        SafetyPolicy::STRICT
+    }
+}
+
+/// Why a pair of adjacent levels breaks the rules
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reason {
+    TooSmall { step: i64, min: i64 },
+    TooLarge { step: i64, max: i64 },
+    WrongDirection { expected: Trend },
+}
+
+impl Display for Reason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Reason::TooSmall { step: 0, .. } => write!(f, "the level doesn't change"),
+            Reason::TooSmall { step, min } => write!(f, "changes by {step}, less than {min}"),
+            Reason::TooLarge { step, max } => write!(f, "changes by {step}, more than {max}"),
+            Reason::WrongDirection { expected: Trend::Increasing } => write!(f, "decreases, but the levels must increase"),
+            Reason::WrongDirection { expected: Trend::Decreasing } => write!(f, "increases, but the levels must decrease"),
+            Reason::WrongDirection { expected: Trend::Either } => write!(f, "changes direction"),
+        }
+    }
+}
+
+/// The first pair of adjacent levels breaking the rules in an unsafe report
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fault {
+    /// Position of the first level of the pair, from 0
+    pub position: usize,
+    pub levels: (i64, i64),
+    pub reason: Reason,
+}
+
+/// How to print the diagnostics
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Table,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "table" => Ok(Format::Table),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("Unknown diagnostics format '{s}'. Expected one of: table, json")),
+        }
+    }
+}
+
+/// Writes the faults found in the unsafe reports, given along with their line numbers.
+/// Positions are written counting from 1.
+pub fn write_faults<W: Write>(out: &mut W, faults: &[(usize, Fault)], format: Format) -> io::Result<()> {
+    match format {
+        Format::Table => {
+            writeln!(out, "{:>6}  {:>8}  {:>11}  reason", "report", "position", "levels")?;
+            for (line, fault) in faults {
+                let levels = format!("{} {}", fault.levels.0, fault.levels.1);
+                writeln!(out, "{line:>6}  {:>8}  {levels:>11}  {}", fault.position + 1, fault.reason)?;
+            }
+        }
+        Format::Json => {
+            writeln!(out, "[")?;
+            for (i, (line, fault)) in faults.iter().enumerate() {
+                let sep = if i + 1 < faults.len() { "," } else { "" };
+                writeln!(out, "  {{\"report\": {line}, \"position\": {}, \"levels\": [{}, {}], \"reason\": \"{}\"}}{sep}",
+                    fault.position + 1, fault.levels.0, fault.levels.1, fault.reason)?;
+            }
+            writeln!(out, "]")?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use aoc_common::Puzzle;
+use day02::{
+    safety::{self, Fault, Reason, SafetyPolicy, Trend},
+    Day02, Report,
+};
+
+fn report(line: &str) -> Report {
+    Day02::parse(line).unwrap().remove(0)
+}
+
+#[test]
+fn default_policy_follows_the_puzzle() {
+    let safe = [true, false, false, false, false, true];
+    let example = ["7 6 4 2 1", "1 2 7 8 9", "9 7 6 2 1", "1 3 2 4 5", "8 6 4 4 1", "1 3 6 7 9"];
+
+    for (line, safe) in example.into_iter().zip(safe) {
+        assert_eq!(report(line).removals(&SafetyPolicy::default()).is_some(), safe, "{line}");
+    }
+}
+
+#[test]
+fn custom_policies() {
+    let wide = SafetyPolicy { max_step: 5, ..SafetyPolicy::STRICT };
+    assert_eq!(report("1 2 7 8 9").removals(&wide), Some(vec![]));
+
+    let increasing = SafetyPolicy { trend: Trend::Increasing, ..SafetyPolicy::STRICT };
+    assert_eq!(report("7 6 4 2 1").removals(&increasing), None);
+    assert_eq!(report("1 3 6 7 9").removals(&increasing), Some(vec![]));
+
+    let flat = SafetyPolicy { min_step: 0, ..SafetyPolicy::STRICT };
+    assert_eq!(report("8 6 4 4 1").removals(&flat), Some(vec![]));
+
+    let tolerant = SafetyPolicy { tolerated_faults: 2, ..SafetyPolicy::STRICT };
+    assert_eq!(report("1 2 9 9 3 4").removals(&tolerant), Some(vec![2, 3]));
+}
+
+#[test]
+fn diagnoses_the_first_offending_pair() {
+    let policy = SafetyPolicy::default();
+
+    assert_eq!(report("7 6 4 2 1").diagnose(&policy), None);
+    assert_eq!(report("1 2 7 8 9").diagnose(&policy),
+        Some(Fault { position: 1, levels: (2, 7), reason: Reason::TooLarge { step: 5, max: 3 } }));
+    assert_eq!(report("1 3 2 4 5").diagnose(&policy),
+        Some(Fault { position: 1, levels: (3, 2), reason: Reason::WrongDirection { expected: Trend::Either } }));
+    assert_eq!(report("8 6 4 4 1").diagnose(&policy),
+        Some(Fault { position: 2, levels: (4, 4), reason: Reason::TooSmall { step: 0, min: 1 } }));
+
+    let decreasing = SafetyPolicy { trend: Trend::Decreasing, ..SafetyPolicy::STRICT };
+    assert_eq!(report("1 3 6 7 9").diagnose(&decreasing),
+        Some(Fault { position: 0, levels: (1, 3), reason: Reason::WrongDirection { expected: Trend::Decreasing } }));
+
+    // Safe thanks to the dampener
+    assert_eq!(report("1 3 2 4 5").diagnose(&SafetyPolicy::DAMPENED), None);
+}
+
+#[test]
+fn writes_the_diagnostics() {
+    let faults = [
+        (2, Fault { position: 1, levels: (2, 7), reason: Reason::TooLarge { step: 5, max: 3 } }),
+        (5, Fault { position: 2, levels: (4, 4), reason: Reason::TooSmall { step: 0, min: 1 } }),
+    ];
+
+    let mut table = vec![];
+    safety::write_faults(&mut table, &faults, safety::Format::Table).unwrap();
+    let table = String::from_utf8(table).unwrap();
+    assert_eq!(table.lines().count(), 3);
+    assert!(table.lines().nth(1).unwrap().ends_with("2 7  changes by 5, more than 3"));
+
+    let mut json = vec![];
+    safety::write_faults(&mut json, &faults, safety::Format::Json).unwrap();
+    assert_eq!(String::from_utf8(json).unwrap(), "[\n  \
+        {\"report\": 2, \"position\": 2, \"levels\": [2, 7], \"reason\": \"changes by 5, more than 3\"},\n  \
+        {\"report\": 5, \"position\": 3, \"levels\": [4, 4], \"reason\": \"the level doesn't change\"}\n]\n");
+}
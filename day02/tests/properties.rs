@@ -0,0 +1,91 @@
+use aoc_common::{Answer, Puzzle};
+use day02::Day02;
+use proptest::prelude::*;
+
+fn count(answer: Answer) -> i64 {
+    match answer {
+        Answer::Number(n) => n,
+        other => panic!("Expected a count, got {other:?}"),
+    }
+}
+
+fn to_input(reports: &[Vec<i64>]) -> String {
+    reports.iter()
+        .map(|r| r.iter().map(|l| l.to_string()).collect::<Vec<_>>().join(" ") + "\n")
+        .collect()
+}
+
+// Levels that change by 1 to 3 at every step, all in the same direction
+fn safe_report() -> impl Strategy<Value = Vec<i64>> {
+    (0..100i64, prop::collection::vec(1..=3i64, 1..8), any::<bool>())
+        .prop_map(|(start, steps, increasing)| {
+            let sign = if increasing { 1 } else { -1 };
+            std::iter::once(start)
+                .chain(steps.into_iter().scan(start, move |level, step| { *level += sign * step; Some(*level) }))
+                .collect()
+        })
+}
+
+fn is_safe(levels: &[i64]) -> bool {
+    let steps = levels.windows(2).map(|w| w[1] - w[0]).collect::<Vec<_>>();
+
+    steps.iter().all(|s| (1..=3).contains(s)) || steps.iter().all(|s| (-3..=-1).contains(s))
+}
+
+fn without(levels: &[i64], removed: &[usize]) -> Vec<i64> {
+    levels.iter().enumerate().filter(|(i, _)| !removed.contains(i)).map(|(_, &l)| l).collect()
+}
+
+// Fewest removals making the levels safe, trying all the combinations
+fn fewest_removals(levels: &[i64], tolerance: usize) -> Option<usize> {
+    fn search(levels: &[i64], from: usize, left: usize, removed: &mut Vec<usize>) -> bool {
+        if is_safe(&without(levels, removed)) {
+            return true;
+        }
+        left > 0 && (from..levels.len()).any(|i| {
+            removed.push(i);
+            let found = search(levels, i + 1, left - 1, removed);
+            removed.pop();
+            found
+        })
+    }
+
+    (0..=tolerance.min(levels.len() - 1)).find(|&k| search(levels, 0, k, &mut vec![]))
+}
+
+proptest! {
+    #[test]
+    fn dampener_removes_as_few_levels_as_possible(levels in prop::collection::vec(0..12i64, 1..9), tolerance in 0..3usize) {
+        let input = levels.iter().map(|l| l.to_string()).collect::<Vec<_>>().join(" ");
+        let report = &Day02::parse(&input).unwrap()[0];
+        let removed = report.dampen(tolerance);
+
+        prop_assert_eq!(removed.as_ref().map(|r| r.len()), fewest_removals(&levels, tolerance));
+        if let Some(removed) = removed {
+            prop_assert!(is_safe(&without(&levels, &removed)));
+        }
+    }
+
+    #[test]
+    fn dampener_never_makes_reports_unsafe(reports in prop::collection::vec(prop::collection::vec(0..20i64, 1..8), 1..20)) {
+        let input = to_input(&reports);
+
+        prop_assert!(count(day02::part1(&input).unwrap()) <= count(day02::part2(&input).unwrap()));
+    }
+
+    #[test]
+    fn safe_reports_are_counted(reports in prop::collection::vec(safe_report(), 1..20)) {
+        let input = to_input(&reports);
+
+        prop_assert_eq!(count(day02::part1(&input).unwrap()), reports.len() as i64);
+        prop_assert_eq!(count(day02::part2(&input).unwrap()), reports.len() as i64);
+    }
+
+    #[test]
+    fn dampener_tolerates_one_bad_level(report in safe_report(), bad in -50..150i64, at in any::<prop::sample::Index>()) {
+        let mut report = report;
+        report.insert(at.index(report.len() + 1), bad);
+
+        prop_assert_eq!(count(day02::part2(&to_input(&[report])).unwrap()), 1);
+    }
+}
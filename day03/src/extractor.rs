@@ -0,0 +1,223 @@
+//! Finding the instructions in corrupted memory.
+//!
+//! The [`Extractor`] is fed the memory one byte at a time. It tries to read an instruction
+//! starting at every position, the way a reader scanning the text would, and skips over the
+//! instructions it finds: they can't overlap. When a candidate instruction turns out to be
+//! broken, the bytes read since its start are read again from the next position, as the next
+//! instruction might start among them.
+//!
+//! Only the bytes of the candidate instruction are kept, so the memory can be read in chunks of
+//! any size, cut anywhere, and be as big as it gets. It doesn't have to be valid UTF-8 either.
+
+use std::{
+    io::{self, ErrorKind, Read},
+    ops::Range,
+};
+
+use crate::instructions::InstructionSet;
+
+/// A recognised instruction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Instruction {
+    /// Index of the instruction in its [`InstructionSet`]
+    pub op: usize,
+    pub args: Vec<i64>,
+    /// Offsets of its first byte and past its last one in the memory
+    pub span: Range<usize>,
+}
+
+impl Instruction {
+    /// The instruction as it would be written without any leading zero or line break
+    pub fn text(&self, set: &InstructionSet) -> String {
+        let args = self.args.iter().map(|a| a.to_string()).collect::<Vec<_>>();
+
+        format!("{}({})", set.op(self.op).name, args.join(","))
+    }
+}
+
+#[derive(Debug)]
+enum State {
+    Name,
+    Args { op: usize, args: Vec<i64>, value: i64, digits: usize },
+}
+
+enum Step {
+    Continue(State),
+    Done(Instruction),
+    Broken,
+}
+
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Reads all of `memory`, handing over one chunk at a time
+pub fn read_chunks<R, F>(mut memory: R, mut chunk_read: F) -> io::Result<()>
+where
+    R: Read,
+    F: FnMut(&[u8]) -> io::Result<()>,
+{
+    let mut chunk = vec![0; CHUNK_SIZE];
+
+    loop {
+        match memory.read(&mut chunk) {
+            Ok(0) => return Ok(()),
+            Ok(n) => chunk_read(&chunk[..n])?,
+            Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+pub struct Extractor<'a> {
+    set: &'a InstructionSet,
+    state: State,
+    /// The bytes of the candidate instruction
+    pending: Vec<u8>,
+    /// And where they are in the memory
+    offsets: Vec<usize>,
+    /// Offset of the next byte
+    position: usize,
+    join_lines: bool,
+}
+
+impl<'a> Extractor<'a> {
+    pub fn new(set: &'a InstructionSet) -> Self {
+        Extractor {
+            set,
+            state: State::Name,
+            pending: vec![],
+            offsets: vec![],
+            position: 0,
+            join_lines: false,
+        }
+    }
+
+    /// Skips the line breaks, as if the memory had been wrapped after the fact: an instruction
+    /// cut by one is still found, its span including the line break. By default, as in the
+    /// puzzle, a line break is corrupted memory like any other byte.
+    pub fn join_lines(mut self, join: bool) -> Self {
+        self.join_lines = join;
+        self
+    }
+
+    /// Offset of the next byte to be read
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Offset of the first byte of the candidate instruction. No instruction can start before
+    /// that anymore.
+    pub fn candidate_start(&self) -> usize {
+        self.offsets.first().copied().unwrap_or(self.position)
+    }
+
+    /// Reads the next byte, returning the instruction it completes if any
+    pub fn push(&mut self, byte: u8) -> Option<Instruction> {
+        let offset = self.position;
+        self.position += 1;
+
+        if self.join_lines && (byte == b'\n' || byte == b'\r') {
+            return None;
+        }
+
+        self.read(offset, byte)
+    }
+
+    // There can't be more than one instruction found per byte: a candidate can only break on
+    // its last byte, as it can't contain a closing parenthesis before that, so an instruction
+    // found while reading the candidate again also ends on that byte.
+    fn read(&mut self, offset: usize, byte: u8) -> Option<Instruction> {
+        self.pending.push(byte);
+        self.offsets.push(offset);
+
+        match self.step(byte) {
+            Step::Continue(state) => {
+                self.state = state;
+                None
+            }
+            Step::Done(instruction) => {
+                self.reset();
+                Some(instruction)
+            }
+            Step::Broken => {
+                let pending = std::mem::take(&mut self.pending);
+                let offsets = std::mem::take(&mut self.offsets);
+                self.state = State::Name;
+
+                let mut found = None;
+                for (&b, &o) in pending[1..].iter().zip(&offsets[1..]) {
+                    if let Some(instruction) = self.read(o, b) {
+                        found = Some(instruction);
+                    }
+                }
+                found
+            }
+        }
+    }
+
+    /// Reads a whole piece of memory, returning the instructions found in it. A candidate
+    /// instruction left unfinished at its end can still be completed by the next piece.
+    pub fn extract(&mut self, bytes: &[u8]) -> Vec<Instruction> {
+        bytes.iter().filter_map(|&b| self.push(b)).collect()
+    }
+
+    /// Reads all of `memory`, chunk by chunk, handing over the instructions as they're found
+    pub fn extract_from<R, F>(&mut self, memory: R, mut found: F) -> io::Result<()>
+    where
+        R: Read,
+        F: FnMut(Instruction),
+    {
+        read_chunks(memory, |chunk| {
+            for &b in chunk {
+                if let Some(instruction) = self.push(b) {
+                    found(instruction);
+                }
+            }
+            Ok(())
+        })
+    }
+
+    /// Forgets about the candidate instruction, if any. The next byte is read as if it were
+    /// the start of some new memory, although the offsets keep going.
+    pub fn reset(&mut self) {
+        self.pending.clear();
+        self.offsets.clear();
+        self.state = State::Name;
+    }
+
+    // What the candidate becomes with `byte`, which was just added to the pending bytes
+    fn step(&mut self, byte: u8) -> Step {
+        let state = std::mem::replace(&mut self.state, State::Name);
+
+        match state {
+            State::Name if byte == b'(' => match self.set.find(&self.pending[..self.pending.len() - 1]) {
+                Some(op) => Step::Continue(State::Args { op, args: vec![], value: 0, digits: 0 }),
+                None => Step::Broken,
+            },
+            State::Name if self.set.starts_name(&self.pending) => Step::Continue(State::Name),
+            State::Name => Step::Broken,
+            State::Args { op, args, value, digits } if byte.is_ascii_digit() && digits < self.set.max_digits => {
+                Step::Continue(State::Args { op, args, value: value * 10 + (byte - b'0') as i64, digits: digits + 1 })
+            }
+            State::Args { op, mut args, value, digits } if byte == b',' && digits > 0 => {
+                args.push(value);
+                if args.len() < *self.set.op(op).arity.end() {
+                    Step::Continue(State::Args { op, args, value: 0, digits: 0 })
+                } else {
+                    Step::Broken
+                }
+            }
+            State::Args { op, mut args, value, digits } if byte == b')' && (digits > 0 || args.is_empty()) => {
+                if digits > 0 {
+                    args.push(value);
+                }
+                if self.set.op(op).arity.contains(&args.len()) {
+                    let span = self.offsets[0]..self.offsets[self.offsets.len() - 1] + 1;
+                    Step::Done(Instruction { op, args, span })
+                } else {
+                    Step::Broken
+                }
+            }
+            State::Args { .. } => Step::Broken,
+        }
+    }
+}
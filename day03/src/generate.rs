@@ -0,0 +1,29 @@
+//! Random corrupted memory: instructions, broken instructions and noise. `size` is the number
+//! of pieces, written in lines of about 60 of them.
+
+use aoc_common::generate::{Params, Rng};
+
+const NOISE: &[&str] = &[
+    "x", "%", "&", "!", "@", "^", "+", "]", "[", "(", ")", "what()", "from()", "select()", "'", " ",
+    "mul[3,7]", "mul(4*", "mul ( 2 , 4 )", "mul(32,64]", "do_not_", "don't", "do(", "mu", "mul(1234,5)",
+];
+
+pub fn input(rng: &mut Rng, params: &Params) -> String {
+    let mut text = String::new();
+
+    for i in 0..params.size {
+        match rng.below(10) {
+            0..4 => text.push_str(&format!("mul({},{})", rng.between(0, 999), rng.between(0, 999))),
+            4 => text.push_str("do()"),
+            5 => text.push_str("don't()"),
+            _ => text.push_str(rng.pick::<&str>(NOISE)),
+        }
+
+        if i % 60 == 59 {
+            text.push('\n');
+        }
+    }
+
+    text.push('\n');
+    text
+}
@@ -0,0 +1,241 @@
+//! The memory printed back with the instructions highlighted.
+//!
+//! Instructions that ran stand out, arithmetic instructions skipped because they were disabled
+//! are dimmed, and the rest of the memory is left as is. The memory goes through as it's read:
+//! only the bytes of the candidate instruction are held back.
+
+use std::{
+    io::{self, Read, Write},
+    str::FromStr,
+};
+
+use crate::{
+    extractor::{read_chunks, Extractor, Instruction},
+    instructions::InstructionSet,
+    interpreter::Interpreter,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    /// Terminal colours
+    Ansi,
+    /// A standalone HTML page
+    Html,
+}
+
+impl FromStr for Style {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ansi" => Ok(Style::Ansi),
+            "html" => Ok(Style::Html),
+            _ => Err(format!("Unknown highlight style '{s}'. Expected one of: ansi, html")),
+        }
+    }
+}
+
+/// How the instructions were treated, by index in the [`InstructionSet`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Counts {
+    pub found: Vec<usize>,
+    pub ran: Vec<usize>,
+}
+
+impl Counts {
+    pub fn skipped(&self, op: usize) -> usize {
+        self.found[op] - self.ran[op]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Ran,
+    Toggle,
+    Skipped,
+}
+
+const HTML_HEADER: &str = "<!DOCTYPE html>
+<html>
+<head>
+<meta charset=\"utf-8\">
+<style>
+  body { background: #1e1e1e; color: #777; }
+  .ran { color: #50c850; font-weight: bold; }
+  .toggle { color: #f0c828; font-weight: bold; }
+  .skipped { color: #555; text-decoration: line-through; }
+  table { color: #ddd; }
+</style>
+</head>
+<body>
+<pre>";
+
+pub struct Highlighter<'a, W: Write> {
+    out: W,
+    style: Style,
+    set: &'a InstructionSet,
+    extractor: Extractor<'a>,
+    interpreter: Interpreter<'a>,
+    /// The bytes not written yet
+    held: Vec<u8>,
+    /// Offset of the first one
+    written: usize,
+    counts: Counts,
+}
+
+impl<'a, W: Write> Highlighter<'a, W> {
+    pub fn new(out: W, set: &'a InstructionSet, style: Style) -> io::Result<Self> {
+        let mut highlighter = Highlighter {
+            out,
+            style,
+            set,
+            extractor: Extractor::new(set),
+            interpreter: Interpreter::new(set),
+            held: vec![],
+            written: 0,
+            counts: Counts { found: vec![0; set.ops().len()], ran: vec![0; set.ops().len()] },
+        };
+
+        if style == Style::Html {
+            writeln!(highlighter.out, "{HTML_HEADER}")?;
+        }
+
+        Ok(highlighter)
+    }
+
+    /// See [`Extractor::join_lines`]
+    pub fn join_lines(mut self, join: bool) -> Self {
+        self.extractor = self.extractor.join_lines(join);
+        self
+    }
+
+    /// Reads the next piece of memory, writing out whatever can't be part of an instruction
+    /// anymore
+    pub fn feed(&mut self, bytes: &[u8]) -> io::Result<()> {
+        for &b in bytes {
+            self.held.push(b);
+
+            if let Some(instruction) = self.extractor.push(b) {
+                self.write_instruction(&instruction)?;
+            }
+        }
+
+        let done = self.extractor.candidate_start();
+        self.write_plain(done)
+    }
+
+    /// Writes the rest of the memory and the summary, returning the counts
+    pub fn finish(mut self) -> io::Result<Counts> {
+        let end = self.extractor.position();
+        self.write_plain(end)?;
+
+        match self.style {
+            Style::Ansi => {
+                writeln!(self.out)?;
+                for (i, op) in self.set.ops().iter().enumerate() {
+                    write!(self.out, "{}: {} found", op.name, self.counts.found[i])?;
+                    if op.effect.is_arithmetic() {
+                        write!(self.out, ", {} ran, {} skipped", self.counts.ran[i], self.counts.skipped(i))?;
+                    }
+                    writeln!(self.out)?;
+                }
+            }
+            Style::Html => {
+                writeln!(self.out, "</pre>\n<table>\n<tr><th>instruction</th><th>found</th><th>ran</th><th>skipped</th></tr>")?;
+                for (i, op) in self.set.ops().iter().enumerate() {
+                    write!(self.out, "<tr><td>")?;
+                    self.write_escaped(op.name.as_bytes())?;
+                    writeln!(self.out, "</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                        self.counts.found[i], self.counts.ran[i], self.counts.skipped(i))?;
+                }
+                writeln!(self.out, "</table>\n</body>\n</html>")?;
+            }
+        }
+
+        self.out.flush()?;
+        Ok(self.counts)
+    }
+
+    fn write_instruction(&mut self, instruction: &Instruction) -> io::Result<()> {
+        let op = instruction.op;
+        let ran = self.interpreter.run(instruction);
+        let kind = match ran {
+            false => Kind::Skipped,
+            true if self.set.op(op).effect.is_arithmetic() => Kind::Ran,
+            true => Kind::Toggle,
+        };
+
+        self.counts.found[op] += 1;
+        if ran {
+            self.counts.ran[op] += 1;
+        }
+
+        self.write_plain(instruction.span.start)?;
+
+        let bytes = self.held.drain(..instruction.span.end - self.written).collect::<Vec<_>>();
+        self.written = instruction.span.end;
+
+        match self.style {
+            Style::Ansi => {
+                let code = match kind {
+                    Kind::Ran => "1;32",
+                    Kind::Toggle => "1;33",
+                    Kind::Skipped => "2;9",
+                };
+                write!(self.out, "\x1b[{code}m")?;
+                self.out.write_all(&bytes)?;
+                write!(self.out, "\x1b[0m")
+            }
+            Style::Html => {
+                let class = match kind {
+                    Kind::Ran => "ran",
+                    Kind::Toggle => "toggle",
+                    Kind::Skipped => "skipped",
+                };
+                write!(self.out, "<span class=\"{class}\">")?;
+                self.write_escaped(&bytes)?;
+                write!(self.out, "</span>")
+            }
+        }
+    }
+
+    // Writes the held bytes up to the offset `end`
+    fn write_plain(&mut self, end: usize) -> io::Result<()> {
+        if end <= self.written {
+            return Ok(());
+        }
+
+        let bytes = self.held.drain(..end - self.written).collect::<Vec<_>>();
+        self.written = end;
+
+        match self.style {
+            Style::Ansi => self.out.write_all(&bytes),
+            Style::Html => self.write_escaped(&bytes),
+        }
+    }
+
+    fn write_escaped(&mut self, bytes: &[u8]) -> io::Result<()> {
+        for &b in bytes {
+            match b {
+                b'<' => self.out.write_all(b"&lt;")?,
+                b'>' => self.out.write_all(b"&gt;")?,
+                b'&' => self.out.write_all(b"&amp;")?,
+                _ => self.out.write_all(&[b])?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Writes the whole memory, highlighted, followed by the summary
+pub fn highlight<R, W>(memory: R, out: W, set: &InstructionSet, style: Style, join_lines: bool) -> io::Result<Counts>
+where
+    R: Read,
+    W: Write,
+{
+    let mut highlighter = Highlighter::new(out, set, style)?.join_lines(join_lines);
+
+    read_chunks(memory, |chunk| highlighter.feed(chunk))?;
+    highlighter.finish()
+}
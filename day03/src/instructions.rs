@@ -0,0 +1,195 @@
+//! The instructions understood by the extractor.
+//!
+//! An instruction is a name, followed by a list of small numbers between parentheses, separated
+//! by commas: `mul(2,4)`, `do()`. An [`InstructionSet`] tells which names are recognised, how
+//! many numbers they take and what they do. It can be written as a comma-separated list of
+//! `name[=effect][/arity]` entries, the effect being the name itself when omitted:
+//!
+//! ```text
+//! mul/2,add/2-4,do=enable,don't=disable
+//! ```
+
+use std::{fmt::Display, ops::RangeInclusive, str::FromStr};
+
+/// What an instruction does when it's run
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Effect {
+    /// Adds its numbers to the total
+    Add,
+    /// Adds the first number minus all the others to the total
+    Sub,
+    /// Adds the product of its numbers to the total
+    Mul,
+    /// Enables the arithmetic instructions that follow
+    Enable,
+    /// Disables the arithmetic instructions that follow
+    Disable,
+    /// Enables them if they were disabled, and the other way round
+    Toggle,
+}
+
+impl Effect {
+    pub const ALL: [Effect; 6] = [Effect::Add, Effect::Sub, Effect::Mul, Effect::Enable, Effect::Disable, Effect::Toggle];
+
+    pub fn is_arithmetic(&self) -> bool {
+        matches!(self, Effect::Add | Effect::Sub | Effect::Mul)
+    }
+
+    /// What an arithmetic instruction adds to the total. Overflows wrap around.
+    pub fn evaluate(&self, args: &[i64]) -> Option<i64> {
+        match self {
+            Effect::Add => Some(args.iter().fold(0, |acc, &n| acc.wrapping_add(n))),
+            Effect::Sub => Some(match args.split_first() {
+                Some((first, rest)) => rest.iter().fold(*first, |acc, &n| acc.wrapping_sub(n)),
+                None => 0,
+            }),
+            Effect::Mul => Some(args.iter().fold(1, |acc, &n| acc.wrapping_mul(n))),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Effect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Effect::Add => "add",
+            Effect::Sub => "sub",
+            Effect::Mul => "mul",
+            Effect::Enable => "enable",
+            Effect::Disable => "disable",
+            Effect::Toggle => "toggle",
+        };
+
+        write!(f, "{name}")
+    }
+}
+
+impl FromStr for Effect {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Effect::ALL.into_iter()
+            .find(|e| e.to_string() == s)
+            .ok_or_else(|| format!("Unknown effect '{s}'. Expected one of: add, sub, mul, enable, disable, toggle"))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Op {
+    pub name: String,
+    pub effect: Effect,
+    /// How many numbers the instruction takes
+    pub arity: RangeInclusive<usize>,
+}
+
+impl Op {
+    /// An instruction with the usual arity for its effect: two numbers for the arithmetic ones,
+    /// none for the others
+    pub fn new(name: &str, effect: Effect) -> Op {
+        let arity = if effect.is_arithmetic() { 2..=2 } else { 0..=0 };
+
+        Op { name: name.to_string(), effect, arity }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstructionSet {
+    ops: Vec<Op>,
+    /// Numbers with more digits than that make the instruction invalid
+    pub max_digits: usize,
+}
+
+impl InstructionSet {
+    /// A set without any instruction, accepting numbers of up to 3 digits
+    pub fn new() -> InstructionSet {
+        InstructionSet { ops: vec![], max_digits: 3 }
+    }
+
+    /// The instructions of the first part of the puzzle
+    pub fn multiplications() -> InstructionSet {
+        let mut set = InstructionSet::new();
+        set.add(Op::new("mul", Effect::Mul)).unwrap();
+        set
+    }
+
+    /// The instructions of the second part, with `do()` and `don't()`
+    pub fn conditional() -> InstructionSet {
+        let mut set = InstructionSet::multiplications();
+        set.add(Op::new("do", Effect::Enable)).unwrap();
+        set.add(Op::new("don't", Effect::Disable)).unwrap();
+        set
+    }
+
+    /// Adds an instruction, whose name must not be empty, contain digits, commas or
+    /// parentheses, or be taken already
+    pub fn add(&mut self, op: Op) -> Result<(), String> {
+        if op.name.is_empty() || op.name.contains(|c: char| c.is_ascii_digit() || "(),".contains(c)) {
+            return Err(format!("'{}' can't be the name of an instruction", op.name));
+        }
+        if self.ops.iter().any(|o| o.name == op.name) {
+            return Err(format!("There are two instructions named '{}'", op.name));
+        }
+        if op.arity.is_empty() {
+            return Err(format!("'{}' has no possible number of arguments", op.name));
+        }
+
+        self.ops.push(op);
+        Ok(())
+    }
+
+    pub fn ops(&self) -> &[Op] {
+        &self.ops
+    }
+
+    pub fn op(&self, index: usize) -> &Op {
+        &self.ops[index]
+    }
+
+    /// The index of the instruction with exactly that name
+    pub(crate) fn find(&self, name: &[u8]) -> Option<usize> {
+        self.ops.iter().position(|op| op.name.as_bytes() == name)
+    }
+
+    /// Whether `bytes` can be the start of the name of an instruction
+    pub(crate) fn starts_name(&self, bytes: &[u8]) -> bool {
+        self.ops.iter().any(|op| op.name.as_bytes().starts_with(bytes))
+    }
+}
+
+impl Default for InstructionSet {
+    fn default() -> Self {
+        InstructionSet::new()
+    }
+}
+
+fn parse_arity(s: &str) -> Option<RangeInclusive<usize>> {
+    match s.split_once('-') {
+        Some((min, max)) => Some(min.parse().ok()?..=max.parse().ok()?),
+        None => s.parse().ok().map(|n| n..=n),
+    }
+}
+
+impl FromStr for InstructionSet {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut set = InstructionSet::new();
+
+        for entry in s.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            let (definition, arity) = match entry.split_once('/') {
+                Some((definition, arity)) => (definition, Some(arity)),
+                None => (entry, None),
+            };
+            let (name, effect) = definition.split_once('=').unwrap_or((definition, definition));
+
+            let mut op = Op::new(name, effect.parse()?);
+            if let Some(arity) = arity {
+                op.arity = parse_arity(arity)
+                    .ok_or_else(|| format!("Not a valid arity for '{name}': {arity}. Expected N or N-M"))?;
+            }
+            set.add(op)?;
+        }
+
+        Ok(set)
+    }
+}
@@ -0,0 +1,42 @@
+//! Running the instructions found by the extractor.
+
+use crate::{extractor::Instruction, instructions::{Effect, InstructionSet}};
+
+pub struct Interpreter<'a> {
+    set: &'a InstructionSet,
+    enabled: bool,
+    total: i64,
+}
+
+impl<'a> Interpreter<'a> {
+    pub fn new(set: &'a InstructionSet) -> Self {
+        Interpreter { set, enabled: true, total: 0 }
+    }
+
+    /// Runs an instruction, telling whether it had any effect: arithmetic instructions are
+    /// skipped while disabled.
+    pub fn run(&mut self, instruction: &Instruction) -> bool {
+        let effect = self.set.op(instruction.op).effect;
+
+        match effect {
+            Effect::Enable => self.enabled = true,
+            Effect::Disable => self.enabled = false,
+            Effect::Toggle => self.enabled = !self.enabled,
+            _ if !self.enabled => return false,
+            _ => {
+                let value = effect.evaluate(&instruction.args).unwrap_or(0);
+                self.total = self.total.wrapping_add(value);
+            }
+        }
+
+        true
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn total(&self) -> i64 {
+        self.total
+    }
+}
@@ -0,0 +1,51 @@
+pub mod extractor;
+pub mod generate;
+pub mod highlight;
+pub mod instructions;
+pub mod interpreter;
+
+use std::io::{self, Read};
+use aoc_common::{puzzle, Answer, ParseResult, Puzzle};
+use extractor::Extractor;
+use instructions::InstructionSet;
+use interpreter::Interpreter;
+
+/// Runs all the instructions of the set found in the memory, returning the total
+pub fn evaluate<R: Read>(memory: R, set: &InstructionSet) -> io::Result<i64> {
+    let mut interpreter = Interpreter::new(set);
+
+    Extractor::new(set).extract_from(memory, |instruction| { interpreter.run(&instruction); })?;
+
+    Ok(interpreter.total())
+}
+
+fn total(memory: &str, set: &InstructionSet) -> i64 {
+    evaluate(memory.as_bytes(), set).expect("Can't fail to read from memory")
+}
+
+pub struct Day03;
+
+impl Puzzle for Day03 {
+    /// The memory, as is: anything goes
+    type Input = String;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part1(memory: &Self::Input) -> Answer {
+        total(memory, &InstructionSet::multiplications()).into()
+    }
+
+    fn part2(memory: &Self::Input) -> Answer {
+        total(memory, &InstructionSet::conditional()).into()
+    }
+}
+
+pub fn part1(input: &str) -> ParseResult<Answer> {
+    puzzle::solve_part1::<Day03>(input)
+}
+
+pub fn part2(input: &str) -> ParseResult<Answer> {
+    puzzle::solve_part2::<Day03>(input)
+}
@@ -1,128 +1,86 @@
-use std::io::{stdin, BufRead, BufReader, Read};
+use std::{
+    io::{stdout, BufWriter},
+    process::exit,
+};
 
-#[derive(Debug)]
-struct Mul {
-    a: usize,
-    b: usize,
-}
+use aoc_common::input::{for_day, reader_for_day};
+use aoc_common::report::report_if_requested;
+use day03::{extractor::{read_chunks, Extractor}, highlight::{self, Style}, instructions::InstructionSet, interpreter::Interpreter};
+
+/// The value following `flag` among the program arguments, if any
+fn arg_value<T>(flag: &str) -> Option<T>
+where
+    T: std::str::FromStr<Err = String>,
+{
+    let value = std::env::args().skip_while(|a| a != flag).nth(1)?;
 
-impl Mul {
-    fn result(&self) -> usize {
-        self.a * self.b
+    match value.parse() {
+        Ok(v) => Some(v),
+        Err(msg) => {
+            eprintln!("{msg}");
+            exit(2);
+        }
     }
 }
 
-#[derive(Debug)]
-enum LexerState {
-    Searching,
-    M,
-    U,
-    L,
-    LParen,
-    Comma,
-    Num1,
-    Num2,
-    D,
-    O,
-    N,
-    Quote,
-    T,
-    EnablingLParen,
-}
+fn main() {
+    let set = arg_value::<InstructionSet>("--instructions");
+    let style = arg_value::<Style>("--highlight");
+    let flag = |name: &str| std::env::args().any(|a| a == name);
+    let (trace, join_lines) = (flag("--trace"), flag("--join-lines"));
 
-struct Extractor {
-    do_dont: bool,
-    enabled: bool,
-}
+    if flag("--format") {
+        let input = for_day(3);
+        report_if_requested(3, &input, day03::part1, day03::part2);
+        return;
+    }
+
+    if let Some(style) = style {
+        let set = set.unwrap_or_else(InstructionSet::conditional);
+        let out = BufWriter::new(stdout().lock());
 
-impl Extractor {
-    fn new(do_dont: bool) -> Self {
-        Extractor {
-            do_dont,
-            enabled: true
+        if let Err(e) = highlight::highlight(reader_for_day(3), out, &set, style, join_lines) {
+            eprintln!("Can't highlight the memory: {e}");
+            exit(1);
         }
+        return;
     }
 
-    fn extract_mults(&mut self, string: &str) -> Vec<Mul> {
-        let mut state = LexerState::Searching;
-        let mut mults = vec![];
-        let mut num1 = String::new();
-        let mut num2 = String::new();
-        let mut enabling = true;
+    // The memory is read only once, in chunks, however big it is
+    let custom = set.is_some() || trace || join_lines;
+    let sets = match set {
+        Some(set) => vec![set],
+        None if custom => vec![InstructionSet::conditional()],
+        None => vec![InstructionSet::multiplications(), InstructionSet::conditional()],
+    };
+    let mut runs = sets.iter()
+        .map(|set| (Extractor::new(set).join_lines(join_lines), Interpreter::new(set)))
+        .collect::<Vec<_>>();
 
-        for c in string.chars() {
-            match state {
-                LexerState::Searching => match c {
-                    'm' => { num1.clear(); num2.clear(); state = LexerState::M },
-                    'd' if self.do_dont => state = LexerState::D,
-                    _ => {}
-                }
-                LexerState::M => state = if c == 'u' { LexerState::U } else { LexerState::Searching },
-                LexerState::U => state = if c == 'l' { LexerState::L } else { LexerState::Searching },
-                LexerState::L => state = if c == '(' { LexerState::LParen } else { LexerState::Searching },
-                LexerState::LParen => state = if c.is_ascii_digit() { num1.push(c); LexerState::Num1 } else { LexerState::Searching },
-                LexerState::Comma => state = if c.is_ascii_digit() { num2.push(c); LexerState::Num2 } else { LexerState::Searching },
-                LexerState::Num1 => state = match c {
-                                        ',' => LexerState::Comma,
-                                        '0'..='9' if num1.len() < 3 => { num1.push(c); LexerState::Num1 },
-                                        _ => LexerState::Searching,
-                                    },
-                LexerState::Num2 => state = match c {
-                                        ')' => {
-                                            if self.enabled {
-                                                mults.push(Mul {
-                                                    a: num1.parse::<usize>().unwrap(),
-                                                    b: num2.parse::<usize>().unwrap(),
-                                                });
-                                            }
-                                            LexerState::Searching
-                                        },
-                                        '0'..='9' if num2.len() < 3 => { num2.push(c); LexerState::Num2 },
-                                        _ => LexerState::Searching,
-                                    },
-                LexerState::D => state = if c == 'o' { LexerState::O } else { LexerState::Searching },
-                LexerState::O => state = match c {
-                                    'n' => { enabling = false; LexerState::N },
-                                    '(' => { enabling = true; LexerState::EnablingLParen },
-                                    _   => LexerState::Searching,
-                                    },
-                LexerState::N => state = if c == '\'' { LexerState::Quote } else { LexerState::Searching },
-                LexerState::Quote => state = if c == 't' { LexerState::T } else { LexerState::Searching },
-                LexerState::T => state = if c == '(' { LexerState::EnablingLParen } else { LexerState::Searching },
-                LexerState::EnablingLParen => {
-                    if c == ')' {
-                        self.enabled = enabling;
-                    }
-                    state = LexerState::Searching;
+    let read = read_chunks(reader_for_day(3), |chunk| {
+        for (set, (extractor, interpreter)) in sets.iter().zip(runs.iter_mut()) {
+            for instruction in extractor.extract(chunk) {
+                let ran = interpreter.run(&instruction);
+
+                if trace {
+                    let span = &instruction.span;
+                    let line = format!("{:>12} {:<20} {}", format!("{}..{}", span.start, span.end),
+                        instruction.text(set), if ran { "" } else { "(disabled)" });
+                    println!("{}", line.trim_end());
                 }
             }
         }
-
-        mults
+        Ok(())
+    });
+    if let Err(e) = read {
+        eprintln!("Can't read the memory: {e}");
+        exit(1);
     }
-}
-
-fn read_samples<R>(stream: BufReader<R>) -> Vec<String>
-    where R: Read,
-{
-    stream.lines().map(|l| l.unwrap().trim().to_string()).collect()
-}
 
-fn main() {
-    let input = read_samples(BufReader::new(stdin()));
-
-    let mut extractor = Extractor::new(false);
-
-    let mul_sums = input.iter()
-                            .map(|l| extractor.extract_mults(&l))
-                            .map(|muls| muls.iter().map(|m| m.result()).sum::<usize>())
-                            .sum::<usize>();
-    println!("Multiplications added: {mul_sums:?}");
-
-    let mut extractor = Extractor::new(true);
-    let mul_sums = input.iter()
-                            .map(|l| extractor.extract_mults(&l))
-                            .map(|muls| muls.iter().map(|m| m.result()).sum::<usize>())
-                            .sum::<usize>();
-    println!("Enabled multiplications added: {mul_sums:?}");
+    if custom {
+        println!("Total: {}", runs[0].1.total());
+    } else {
+        println!("Multiplications added: {}", runs[0].1.total());
+        println!("Enabled multiplications added: {}", runs[1].1.total());
+    }
 }
@@ -0,0 +1,50 @@
+use day03::{
+    highlight::{highlight, Counts, Highlighter, Style},
+    instructions::InstructionSet,
+};
+
+const MEMORY: &[u8] = b"xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+fn highlighted(memory: &[u8], style: Style) -> (String, Counts) {
+    let mut out = vec![];
+    let counts = highlight(memory, &mut out, &InstructionSet::conditional(), style, false).unwrap();
+
+    (String::from_utf8(out).unwrap(), counts)
+}
+
+#[test]
+fn highlights_with_ansi_colours() {
+    let (out, counts) = highlighted(MEMORY, Style::Ansi);
+
+    assert!(out.starts_with("x\x1b[1;32mmul(2,4)\x1b[0m&mul[3,7]!^\x1b[1;33mdon't()\x1b[0m_\x1b[2;9mmul(5,5)\x1b[0m+"));
+    assert!(out.ends_with("\x1b[1;32mmul(8,5)\x1b[0m)\nmul: 4 found, 2 ran, 2 skipped\ndo: 1 found\ndon't: 1 found\n"));
+    assert_eq!(counts, Counts { found: vec![4, 1, 1], ran: vec![2, 1, 1] });
+}
+
+#[test]
+fn highlights_as_html() {
+    let (out, _) = highlighted(MEMORY, Style::Html);
+
+    assert!(out.starts_with("<!DOCTYPE html>"));
+    assert!(out.contains("x<span class=\"ran\">mul(2,4)</span>&amp;mul[3,7]"));
+    assert!(out.contains("<span class=\"skipped\">mul(11,8)</span>un<span class=\"toggle\">do()</span>"));
+    assert!(out.contains("<tr><td>mul</td><td>4</td><td>2</td><td>2</td></tr>"));
+    assert!(out.trim_end().ends_with("</html>"));
+}
+
+#[test]
+fn keeps_the_memory_whatever_the_pieces() {
+    let set = InstructionSet::conditional();
+    let (whole, _) = highlighted(MEMORY, Style::Ansi);
+
+    for size in 1..8 {
+        let mut out = vec![];
+        let mut highlighter = Highlighter::new(&mut out, &set, Style::Ansi).unwrap();
+        for piece in MEMORY.chunks(size) {
+            highlighter.feed(piece).unwrap();
+        }
+        highlighter.finish().unwrap();
+
+        assert_eq!(String::from_utf8(out).unwrap(), whole, "pieces of {size} bytes");
+    }
+}
@@ -0,0 +1,76 @@
+use day03::{
+    evaluate,
+    extractor::Extractor,
+    instructions::{Effect, InstructionSet, Op},
+    interpreter::Interpreter,
+};
+
+fn run(set: &InstructionSet, memory: &str) -> i64 {
+    evaluate(memory.as_bytes(), set).unwrap()
+}
+
+#[test]
+fn parses_instruction_sets() {
+    let set = "mul/2,add/2-4,do=enable,don't=disable".parse::<InstructionSet>().unwrap();
+
+    assert_eq!(set.ops(), [
+        Op::new("mul", Effect::Mul),
+        Op { arity: 2..=4, ..Op::new("add", Effect::Add) },
+        Op::new("do", Effect::Enable),
+        Op::new("don't", Effect::Disable),
+    ]);
+    assert_eq!(set, "mul,add/2-4,do=enable,don't=disable".parse().unwrap());
+
+    assert!("mul,mul".parse::<InstructionSet>().is_err());
+    assert!("m(ul".parse::<InstructionSet>().is_err());
+    assert!("mul=div".parse::<InstructionSet>().is_err());
+    assert!("mul/3-2".parse::<InstructionSet>().is_err());
+    assert!("mul/x".parse::<InstructionSet>().is_err());
+}
+
+#[test]
+fn reports_spans() {
+    let set = InstructionSet::conditional();
+    let memory = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)";
+    let found = Extractor::new(&set).extract(memory.as_bytes());
+
+    let spans = found.iter().map(|i| &memory[i.span.clone()]).collect::<Vec<_>>();
+    assert_eq!(spans, ["mul(2,4)", "don't()", "mul(5,5)"]);
+    assert_eq!(found[0].args, [2, 4]);
+    assert_eq!(set.op(found[1].op).effect, Effect::Disable);
+}
+
+#[test]
+fn runs_extended_instructions() {
+    let set = "mul/1-3,add/1-5,sub/2,flip=toggle".parse::<InstructionSet>().unwrap();
+
+    assert_eq!(run(&set, "add(1,2,3)sub(10,4)mul(2,3,4)"), 6 + 6 + 24);
+    assert_eq!(run(&set, "add(1,2,3,4,5,6)sub(1)sub(1,2,3)mul()"), 0);
+    assert_eq!(run(&set, "mul(5)flip()add(100)flip()sub(1,9)"), 5 - 8);
+    assert_eq!(run(&set, "adadd(1)mumul(2)susub(3,1)"), 1 + 2 + 2);
+}
+
+#[test]
+fn skips_disabled_instructions() {
+    let set = InstructionSet::conditional();
+    let mut extractor = Extractor::new(&set);
+    let mut interpreter = Interpreter::new(&set);
+
+    let ran = extractor.extract(b"mul(1,2)don't()mul(3,4)do()mul(5,6)")
+        .iter()
+        .map(|i| interpreter.run(i))
+        .collect::<Vec<_>>();
+
+    assert_eq!(ran, [true, true, false, true, true]);
+    assert_eq!(interpreter.total(), 2 + 30);
+    assert!(interpreter.enabled());
+}
+
+#[test]
+fn limits_digits() {
+    let mut set = InstructionSet::multiplications();
+    assert_eq!(run(&set, "mul(1234,5)mul(123,5)"), 615);
+
+    set.max_digits = 4;
+    assert_eq!(run(&set, "mul(1234,5)mul(123,5)"), 6170 + 615);
+}
@@ -0,0 +1,94 @@
+use aoc_common::Answer;
+use proptest::prelude::*;
+
+// Reads a number of 1 to 3 digits at the start of `s`, returning it and its length
+fn small_number(s: &[u8]) -> Option<(usize, usize)> {
+    let len = s.iter().take(4).take_while(|c| c.is_ascii_digit()).count();
+
+    if (1..=3).contains(&len) {
+        Some((std::str::from_utf8(&s[..len]).unwrap().parse().unwrap(), len))
+    } else {
+        None
+    }
+}
+
+// Tries to read `mul(a,b)` at the start of `s`
+fn mul_at(s: &[u8]) -> Option<usize> {
+    let rest = s.strip_prefix(b"mul(")?;
+    let (a, len_a) = small_number(rest)?;
+    let rest = rest[len_a..].strip_prefix(b",")?;
+    let (b, len_b) = small_number(rest)?;
+    rest[len_b..].strip_prefix(b")")?;
+
+    Some(a * b)
+}
+
+// Checks every position of the input for an instruction, which can't overlap each other
+fn reference(input: &str, do_dont: bool) -> usize {
+    let mut enabled = true;
+    let mut total = 0;
+
+    for line in input.lines().map(|l| l.trim().as_bytes()) {
+        for start in 0..line.len() {
+            let s = &line[start..];
+            if do_dont && s.starts_with(b"do()") {
+                enabled = true;
+            } else if do_dont && s.starts_with(b"don't()") {
+                enabled = false;
+            } else if let Some(product) = mul_at(s) {
+                if enabled {
+                    total += product;
+                }
+            }
+        }
+    }
+
+    total
+}
+
+// Text made of pieces of instructions, so that both valid and broken ones show up often
+fn program() -> impl Strategy<Value = String> {
+    let piece = prop_oneof![
+        Just("mul(".to_string()),
+        Just("mul".to_string()),
+        Just("m".to_string()),
+        Just("d".to_string()),
+        Just("do()".to_string()),
+        Just("don't()".to_string()),
+        Just("do(".to_string()),
+        Just("don't".to_string()),
+        Just(",".to_string()),
+        Just(")".to_string()),
+        Just("\n".to_string()),
+        "mul\\([0-9]{1,3},[0-9]{1,3}\\)",
+        "[0-9]{1,4}",
+        "[a-z '()!,]",
+    ];
+
+    prop::collection::vec(piece, 0..60).prop_map(|pieces| pieces.concat())
+}
+
+fn number(answer: Answer) -> usize {
+    match answer {
+        Answer::Number(n) => n as usize,
+        other => panic!("Expected a number, got {other:?}"),
+    }
+}
+
+proptest! {
+    #[test]
+    fn finds_every_multiplication(input in program()) {
+        prop_assert_eq!(number(day03::part1(&input).unwrap()), reference(&input, false));
+    }
+
+    #[test]
+    fn honours_do_and_dont(input in program()) {
+        prop_assert_eq!(number(day03::part2(&input).unwrap()), reference(&input, true));
+    }
+
+    #[test]
+    fn survives_any_text(input in "\\PC*") {
+        prop_assert!(day03::part1(&input).is_ok());
+        prop_assert!(day03::part2(&input).is_ok());
+    }
+}
@@ -0,0 +1,66 @@
+use std::io::{self, Read};
+
+use day03::{evaluate, extractor::Extractor, instructions::InstructionSet};
+
+// Hands over the memory a few bytes at a time
+struct Trickle<'a> {
+    memory: &'a [u8],
+    size: usize,
+}
+
+impl Read for Trickle<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.size.min(buf.len()).min(self.memory.len());
+        buf[..n].copy_from_slice(&self.memory[..n]);
+        self.memory = &self.memory[n..];
+        Ok(n)
+    }
+}
+
+const MEMORY: &[u8] = b"xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+#[test]
+fn reads_memory_in_chunks_of_any_size() {
+    let set = InstructionSet::conditional();
+
+    for size in 1..=MEMORY.len() {
+        assert_eq!(evaluate(Trickle { memory: MEMORY, size }, &set).unwrap(), 48, "chunks of {size} bytes");
+    }
+}
+
+#[test]
+fn keeps_instructions_cut_between_pieces() {
+    let set = InstructionSet::multiplications();
+    let mut extractor = Extractor::new(&set);
+
+    assert!(extractor.extract(b"abcmul(12").is_empty());
+    let found = extractor.extract(b"3,4)mu");
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].args, [123, 4]);
+    assert_eq!(found[0].span, 3..13);
+    assert_eq!(found[0].text(&set), "mul(123,4)");
+
+    assert_eq!(extractor.extract(b"l(0,007)")[0].span, 13..23);
+    assert_eq!(extractor.position(), 23);
+}
+
+#[test]
+fn handles_any_bytes() {
+    let set = InstructionSet::multiplications();
+    let memory = [b"\xff\xfemul(2,3)\x00".as_slice(), "é".as_bytes(), b"mul(\xc3\xa9)mul(4,5)"].concat();
+
+    assert_eq!(evaluate(memory.as_slice(), &set).unwrap(), 26);
+}
+
+#[test]
+fn joins_lines_on_request() {
+    let set = InstructionSet::multiplications();
+    let memory = b"mul(2,\n3)mu\r\nl(4,5)";
+
+    let found = Extractor::new(&set).extract(memory);
+    assert!(found.is_empty());
+
+    let found = Extractor::new(&set).join_lines(true).extract(memory);
+    assert_eq!(found.iter().map(|i| i.args.clone()).collect::<Vec<_>>(), [vec![2, 3], vec![4, 5]]);
+    assert_eq!(found[1].span, 9..19);
+}
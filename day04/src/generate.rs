@@ -0,0 +1,41 @@
+//! Random letter grids with some XMAS and X-MAS hidden in them. `size` is the side of the grid.
+
+use aoc_common::{
+    generate::{Params, Rng},
+    Compass, Grid, Point,
+};
+
+pub fn input(rng: &mut Rng, params: &Params) -> String {
+    let side = params.size.max(3);
+    let mut grid = Grid::new(side, side, 'X');
+
+    for p in grid.points().collect::<Vec<_>>() {
+        grid[p] = *rng.pick(&['X', 'M', 'A', 'S']);
+    }
+
+    for _ in 0..side * side / 20 {
+        let start = Point::from_row_col(rng.index(side), rng.index(side));
+        let heading = *rng.pick(&Compass::ALL);
+
+        let cells = grid.ray(start, heading).take(4).collect::<Vec<_>>();
+        if cells.len() == 4 {
+            for (p, c) in cells.into_iter().zip("XMAS".chars()) {
+                grid[p] = c;
+            }
+        }
+    }
+
+    for _ in 0..side * side / 40 {
+        let center = Point::from_row_col(rng.between(1, side as i64 - 2) as usize, rng.between(1, side as i64 - 2) as usize);
+        let (a, b) = if rng.chance(0.5) { ('M', 'S') } else { ('S', 'M') };
+        let (c, d) = if rng.chance(0.5) { ('M', 'S') } else { ('S', 'M') };
+
+        grid[center] = 'A';
+        grid[center.step_towards(Compass::NW)] = a;
+        grid[center.step_towards(Compass::SE)] = b;
+        grid[center.step_towards(Compass::NE)] = c;
+        grid[center.step_towards(Compass::SW)] = d;
+    }
+
+    grid.rows().map(|r| r.iter().collect::<String>() + "\n").collect()
+}
@@ -0,0 +1,128 @@
+pub mod generate;
+pub mod search;
+pub mod template;
+
+use std::{collections::HashMap, io::{BufReader, Read}};
+use aoc_common::{error::numbered_lines, puzzle, Answer, Grid, ParseResult, Point, Puzzle};
+
+/// Two MAS crossing on their A, either way round
+pub const X_MAS: &str = "M.S/.A./M.S";
+
+pub struct Board {
+    grid: Grid<char>,
+    /// Where every letter is, in reading order
+    letters: HashMap<char, Vec<Point>>,
+    /// Whether the edges are joined, left to right and top to bottom
+    wrap: bool,
+}
+
+/// What to do with rows shorter than the others
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ragged {
+    /// Refuse them, as the puzzle input is a rectangle
+    Reject,
+    /// Fill them up to the length of the longest row
+    Pad(char),
+}
+
+impl Board {
+    pub fn new(grid: Grid<char>) -> Board {
+        let mut letters: HashMap<char, Vec<Point>> = HashMap::new();
+        for (p, &c) in grid.iter() {
+            letters.entry(c).or_default().push(p);
+        }
+
+        Board { grid, letters, wrap: false }
+    }
+
+    /// Makes the board a torus, where words and templates can continue across the edges.
+    /// They still can't use the same cell twice.
+    pub fn wrapping(mut self, wrap: bool) -> Board {
+        self.wrap = wrap;
+        self
+    }
+
+    pub fn wraps(&self) -> bool {
+        self.wrap
+    }
+
+    pub fn grid(&self) -> &Grid<char> {
+        &self.grid
+    }
+
+    fn cells_with(&self, letter: char) -> &[Point] {
+        self.letters.get(&letter).map_or(&[], |cells| cells.as_slice())
+    }
+
+    /// The cell at `p`, which is brought back on the board if it wraps
+    fn cell(&self, p: Point) -> Option<Point> {
+        if self.wrap && self.grid.width() > 0 && self.grid.height() > 0 {
+            let (w, h) = (self.grid.width() as i32, self.grid.height() as i32);
+            Some(Point::new(p.x.rem_euclid(w), p.y.rem_euclid(h)))
+        } else {
+            self.grid.contains(p).then_some(p)
+        }
+    }
+
+    fn letter_at(&self, p: Point) -> Option<char> {
+        self.cell(p).map(|p| self.grid[p])
+    }
+}
+
+/// Reads a board, up to the end of the stream or the first blank line
+pub fn read_board<R>(stream: BufReader<R>, ragged: Ragged) -> ParseResult<Board>
+    where R: Read,
+{
+    let Ragged::Pad(fill) = ragged else {
+        return Grid::parse_chars(stream).map(Board::new);
+    };
+
+    let mut rows = vec![];
+    for line in numbered_lines(stream) {
+        let (_, line) = line?;
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        rows.push(line.chars().collect::<Vec<_>>());
+    }
+
+    let width = rows.iter().map(|r| r.len()).max().unwrap_or(0);
+    for row in rows.iter_mut() {
+        row.resize(width, fill);
+    }
+
+    Ok(Board::new(Grid::from_rows(rows)))
+}
+
+fn read_input<R>(stream: BufReader<R>) -> ParseResult<Board>
+    where R: Read,
+{
+    read_board(stream, Ragged::Reject)
+}
+
+pub struct Day04;
+
+impl Puzzle for Day04 {
+    type Input = Board;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        read_input(BufReader::new(input.as_bytes()))
+    }
+
+    fn part1(board: &Self::Input) -> Answer {
+        board.count_word("XMAS").into()
+    }
+
+    fn part2(board: &Self::Input) -> Answer {
+        board.count_template(&X_MAS.parse().unwrap()).into()
+    }
+}
+
+pub fn part1(input: &str) -> ParseResult<Answer> {
+    puzzle::solve_part1::<Day04>(input)
+}
+
+pub fn part2(input: &str) -> ParseResult<Answer> {
+    puzzle::solve_part2::<Day04>(input)
+}
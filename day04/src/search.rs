@@ -0,0 +1,66 @@
+//! Word search: words hidden in a board, in any of the eight directions.
+
+use aoc_common::{Compass, Point};
+
+use crate::Board;
+
+/// A word found on the board
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Match {
+    /// Index of the word in the list searched for
+    pub word: usize,
+    /// Cell of the first letter
+    pub start: Point,
+    /// Direction the word is read in
+    pub heading: Compass,
+    /// Cell of the last letter, which may be before the first one if the word wraps around
+    pub end: Point,
+}
+
+impl Board {
+    /// Finds every occurrence of the words, in reading order of their first letter. A word
+    /// reading the same both ways is found twice, once per direction, except for single
+    /// letters which are only found once, heading east.
+    pub fn find_words<S: AsRef<str>>(&self, words: &[S]) -> Vec<Match> {
+        let mut matches = vec![];
+
+        for (index, word) in words.iter().enumerate() {
+            let letters = word.as_ref().chars().collect::<Vec<_>>();
+            let Some(first) = letters.first() else { continue };
+            let headings = if letters.len() == 1 { &[Compass::E][..] } else { &Compass::ALL[..] };
+
+            for &start in self.cells_with(*first) {
+                for &heading in headings {
+                    if let Some(end) = self.read(start, heading, &letters) {
+                        matches.push(Match { word: index, start, heading, end });
+                    }
+                }
+            }
+        }
+
+        matches.sort_by_key(|m| (m.start, m.word));
+        matches
+    }
+
+    pub fn count_word(&self, word: &str) -> usize {
+        self.find_words(&[word]).len()
+    }
+
+    // Whether `letters` can be read from `start`, returning the cell of the last letter
+    fn read(&self, start: Point, heading: Compass, letters: &[char]) -> Option<Point> {
+        let mut cells: Vec<Point> = Vec::with_capacity(letters.len());
+        let mut p = start;
+
+        for (i, &letter) in letters.iter().enumerate() {
+            if i > 0 {
+                p = self.cell(p.step_towards(heading))?;
+            }
+            if self.grid[p] != letter || (self.wraps() && cells.contains(&p)) {
+                return None;
+            }
+            cells.push(p);
+        }
+
+        Some(p)
+    }
+}
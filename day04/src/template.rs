@@ -0,0 +1,134 @@
+//! Shapes made of letters, matched against a board in any orientation.
+//!
+//! A template is a small grid of letters where `.` matches anything. It's written one row per
+//! line, or with rows separated by `/`: the X-MAS of the puzzle is `M.S/.A./M.S`. Templates are
+//! found rotated and reflected too.
+
+use std::str::FromStr;
+
+use aoc_common::{Grid, Point};
+
+use crate::Board;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Template {
+    /// `None` for the wildcards
+    cells: Grid<Option<char>>,
+}
+
+/// Where a template was found
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Placement {
+    /// Cell of the top left corner of the template, on the board even if the template wraps
+    pub origin: Point,
+    /// Index of the orientation in [`Template::variants`]
+    pub variant: usize,
+}
+
+impl Template {
+    pub fn width(&self) -> usize {
+        self.cells.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.height()
+    }
+
+    /// The template turned a quarter clockwise
+    pub fn rotate(&self) -> Template {
+        let (w, h) = (self.width(), self.height());
+        let rows = (0..w)
+            .map(|y| (0..h).map(|x| self.cells[Point::from_row_col(h - 1 - x, y)]).collect())
+            .collect();
+
+        Template { cells: Grid::from_rows(rows) }
+    }
+
+    /// The template mirrored left to right
+    pub fn reflect(&self) -> Template {
+        let rows = self.cells.rows()
+            .map(|row| row.iter().rev().copied().collect())
+            .collect();
+
+        Template { cells: Grid::from_rows(rows) }
+    }
+
+    /// The different orientations of the template, as it is first. A symmetric template has
+    /// less than eight of them, so that it isn't found several times at the same place.
+    pub fn variants(&self) -> Vec<Template> {
+        let mut variants: Vec<Template> = vec![];
+        let mut current = self.clone();
+
+        for _ in 0..4 {
+            for variant in [current.clone(), current.reflect()] {
+                if !variants.contains(&variant) {
+                    variants.push(variant);
+                }
+            }
+            current = current.rotate();
+        }
+
+        variants
+    }
+
+    /// The letters that must be there, relative to the top left corner
+    fn letters(&self) -> Vec<(Point, char)> {
+        self.cells.iter().filter_map(|(p, c)| c.map(|c| (p, c))).collect()
+    }
+}
+
+impl FromStr for Template {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rows = s.split(['\n', '/'])
+            .map(str::trim_end)
+            .filter(|r| !r.is_empty())
+            .map(|r| r.chars().map(|c| (c != '.').then_some(c)).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        if rows.is_empty() {
+            return Err("A template needs at least one row".to_string());
+        }
+        if rows.iter().any(|r| r.len() != rows[0].len()) {
+            return Err(format!("All the rows of the template '{s}' must have the same length"));
+        }
+        if rows.iter().flatten().all(|c| c.is_none()) {
+            return Err(format!("The template '{s}' has nothing but wildcards"));
+        }
+
+        Ok(Template { cells: Grid::from_rows(rows) })
+    }
+}
+
+impl Board {
+    /// Finds every place where the template fits, in any orientation, sorted by origin
+    pub fn find_template(&self, template: &Template) -> Vec<Placement> {
+        let mut placements = vec![];
+
+        for (index, variant) in template.variants().iter().enumerate() {
+            let letters = variant.letters();
+            let (anchor, first) = letters[0];
+
+            // The template can only be where its first letter is
+            for &p in self.cells_with(first) {
+                let Some(origin) = self.cell(p.offset(-anchor.x, -anchor.y)) else { continue };
+                let fits = variant.width() <= self.grid.width()
+                    && variant.height() <= self.grid.height()
+                    && (self.wraps() || self.grid.contains(origin.offset(variant.width() as i32 - 1, variant.height() as i32 - 1)))
+                    && letters.iter().all(|&(q, c)| self.letter_at(origin.offset(q.x, q.y)) == Some(c));
+
+                if fits {
+                    placements.push(Placement { origin, variant: index });
+                }
+            }
+        }
+
+        placements.sort_by_key(|p| (p.origin, p.variant));
+        placements
+    }
+
+    pub fn count_template(&self, template: &Template) -> usize {
+        self.find_template(template).len()
+    }
+}
@@ -0,0 +1,50 @@
+use std::io::BufReader;
+
+use aoc_common::{Compass, Point};
+use day04::{read_board, search::Match, template::Template, Board, Ragged};
+
+fn board(s: &str, ragged: Ragged) -> Board {
+    read_board(BufReader::new(s.as_bytes()), ragged).unwrap()
+}
+
+#[test]
+fn rejects_or_pads_ragged_rows() {
+    let ragged = "ABC\nAB\nABCD\n";
+
+    let e = read_board(BufReader::new(ragged.as_bytes()), Ragged::Reject).err().unwrap();
+    assert_eq!((e.line, e.column), (2, 3));
+
+    let padded = board(ragged, Ragged::Pad('.'));
+    assert_eq!((padded.grid().width(), padded.grid().height()), (4, 3));
+    assert_eq!(padded.grid().row(1), ['A', 'B', '.', '.']);
+    assert_eq!(padded.count_word("ABC"), 4);
+}
+
+#[test]
+fn words_wrap_around_the_edges() {
+    let hard = board("MASX\nABCD\n", Ragged::Reject);
+    assert_eq!(hard.count_word("XMAS"), 0);
+
+    let torus = hard.wrapping(true);
+    assert_eq!(torus.find_words(&["XMAS"]), [
+        Match { word: 0, start: Point::new(3, 0), heading: Compass::E, end: Point::new(2, 0) },
+    ]);
+    assert_eq!(torus.count_word("DA"), 1);
+    // Going round the whole row would read the M twice
+    assert_eq!(torus.count_word("MASXM"), 0);
+}
+
+#[test]
+fn templates_wrap_around_the_edges() {
+    let hard = board("A..B\n....\nC..D\n", Ragged::Reject);
+    let square = "DC/BA".parse::<Template>().unwrap();
+    assert_eq!(hard.count_template(&square), 0);
+
+    let torus = hard.wrapping(true);
+    let found = torus.find_template(&square);
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].origin, Point::new(3, 2));
+
+    // Bigger than the board: it would overlap itself
+    assert_eq!(torus.count_template(&"A..B.A".parse().unwrap()), 0);
+}
@@ -0,0 +1,34 @@
+use aoc_common::{Compass, Point, Puzzle};
+use day04::{search::Match, Day04};
+
+const BOARD: &str = "\
+CATS
+OXAO
+WDOG
+";
+
+#[test]
+fn finds_words_in_every_direction() {
+    let board = Day04::parse(BOARD).unwrap();
+    let matches = board.find_words(&["CAT", "DOG", "COW", "TAC", "GOD", "SAD"]);
+
+    assert_eq!(matches, [
+        Match { word: 0, start: Point::new(0, 0), heading: Compass::E, end: Point::new(2, 0) },
+        Match { word: 2, start: Point::new(0, 0), heading: Compass::S, end: Point::new(0, 2) },
+        Match { word: 3, start: Point::new(2, 0), heading: Compass::W, end: Point::new(0, 0) },
+        Match { word: 5, start: Point::new(3, 0), heading: Compass::SW, end: Point::new(1, 2) },
+        Match { word: 1, start: Point::new(1, 2), heading: Compass::E, end: Point::new(3, 2) },
+        Match { word: 4, start: Point::new(3, 2), heading: Compass::W, end: Point::new(1, 2) },
+    ]);
+}
+
+#[test]
+fn counts_palindromes_and_letters() {
+    let board = Day04::parse("ABA\nBAB\nABA\n").unwrap();
+
+    assert_eq!(board.count_word("A"), 5);
+    assert_eq!(board.count_word("ABA"), 8);
+    assert_eq!(board.count_word("AAA"), 4);
+    assert_eq!(board.count_word("ABAB"), 0);
+    assert_eq!(board.count_word(""), 0);
+}
@@ -0,0 +1,55 @@
+use aoc_common::{Point, Puzzle};
+use day04::{template::{Placement, Template}, Day04, X_MAS};
+
+fn template(s: &str) -> Template {
+    s.parse().unwrap()
+}
+
+#[test]
+fn parses_templates() {
+    let t = template("AB.\n..C");
+    assert_eq!((t.width(), t.height()), (3, 2));
+    assert_eq!(t, template("AB./..C"));
+
+    assert!("".parse::<Template>().is_err());
+    assert!("AB/C".parse::<Template>().is_err());
+    assert!("../..".parse::<Template>().is_err());
+}
+
+#[test]
+fn turns_templates() {
+    let t = template("AB./..C");
+
+    assert_eq!(t.rotate(), template(".A/.B/C."));
+    assert_eq!(t.reflect(), template(".BA/C.."));
+    assert_eq!(t.rotate().rotate().rotate().rotate(), t);
+}
+
+#[test]
+fn counts_each_orientation_once() {
+    assert_eq!(template(X_MAS).variants().len(), 4);
+    assert_eq!(template(".A./AAA/.A.").variants().len(), 1);
+    assert_eq!(template("AB/BA").variants().len(), 2);
+    assert_eq!(template("ABC").variants().len(), 4);
+    assert_eq!(template("AB./..C").variants().len(), 8);
+}
+
+#[test]
+fn finds_shapes() {
+    let board = Day04::parse("\
+XAXXX
+AAAXB
+XAXBB
+XXXXX
+").unwrap();
+
+    let plus = board.find_template(&template(".A./AAA/.A."));
+    assert_eq!(plus, [Placement { origin: Point::new(0, 0), variant: 0 }]);
+
+    let square = template("BB/.B");
+    let found = board.find_template(&square);
+    assert_eq!(found, [Placement { origin: Point::new(3, 1), variant: 2 }]);
+    assert_eq!(square.variants()[2], template("B./BB").rotate().rotate().rotate());
+
+    assert_eq!(board.count_template(&template("AAAA")), 0);
+}
@@ -0,0 +1,144 @@
+//! Checks of the rule set as a whole, and what follows from it.
+
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fmt::Display,
+};
+
+use crate::{PageList, RuleLine, Rules};
+
+/// Something odd about the rules. Lines and updates are numbered from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Finding {
+    /// The same rule, written more than once
+    Duplicate { before: usize, after: usize, lines: Vec<usize> },
+    /// `a|b` and `b|a`
+    Contradiction { before: usize, after: usize, lines: (usize, usize) },
+    /// `a|a`
+    SelfReference { page: usize, line: usize },
+    /// A page printed in some updates without any rule about it
+    Unruled { page: usize, updates: Vec<usize> },
+}
+
+impl Display for Finding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let numbers = |ns: &[usize]| ns.iter().map(|n| n.to_string()).collect::<Vec<_>>().join(", ");
+
+        match self {
+            Finding::Duplicate { before, after, lines } => {
+                write!(f, "{before}|{after} is written {} times, on lines {}", lines.len(), numbers(lines))
+            }
+            Finding::Contradiction { before, after, lines: (l1, l2) } => {
+                write!(f, "{before}|{after} on line {l1} contradicts {after}|{before} on line {l2}")
+            }
+            Finding::SelfReference { page, line } => write!(f, "{page}|{page} on line {line} can't be followed"),
+            Finding::Unruled { page, updates } => {
+                write!(f, "{page} has no rule, but is printed in updates {}", numbers(updates))
+            }
+        }
+    }
+}
+
+/// Everything odd about the rules, in the order of the lines of the input, pages without rules
+/// last
+pub fn lint(rules: &[RuleLine], updates: &[PageList]) -> Vec<Finding> {
+    let mut findings = vec![];
+    let mut lines_of: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+
+    for rule in rules {
+        lines_of.entry((rule.before, rule.after)).or_default().push(rule.line);
+    }
+
+    for rule in rules {
+        let (before, after) = (rule.before, rule.after);
+        let lines = &lines_of[&(before, after)];
+
+        // Every problem is reported once, on the first line involved
+        if lines[0] != rule.line {
+            continue;
+        }
+
+        if lines.len() > 1 {
+            findings.push(Finding::Duplicate { before, after, lines: lines.clone() });
+        }
+        if before == after {
+            findings.push(Finding::SelfReference { page: before, line: rule.line });
+        } else if let Some(reversed) = lines_of.get(&(after, before)).filter(|l| l[0] > rule.line) {
+            findings.push(Finding::Contradiction { before, after, lines: (rule.line, reversed[0]) });
+        }
+    }
+
+    let ruled = rules.iter().flat_map(|r| [r.before, r.after]).collect::<HashSet<_>>();
+    let mut unruled: Vec<(usize, Vec<usize>)> = vec![];
+
+    for (n, update) in updates.iter().enumerate() {
+        for &page in update.iter().filter(|p| !ruled.contains(p)) {
+            match unruled.iter_mut().find(|(p, _)| *p == page) {
+                Some((_, updates)) if updates.last() == Some(&(n + 1)) => {}
+                Some((_, updates)) => updates.push(n + 1),
+                None => unruled.push((page, vec![n + 1])),
+            }
+        }
+    }
+
+    findings.extend(unruled.into_iter().map(|(page, updates)| Finding::Unruled { page, updates }));
+    findings
+}
+
+/// What the rules imply, following them from page to page
+pub struct Closure<'a> {
+    rules: &'a Rules,
+}
+
+impl<'a> Closure<'a> {
+    pub fn new(rules: &'a Rules) -> Self {
+        Closure { rules }
+    }
+
+    /// Whether the rules put `a` before `b`, directly or through other pages. If they do,
+    /// returns the shortest chain of pages from `a` to `b`, both included.
+    ///
+    /// With the rules of the puzzle, which have cycles, every page may end up before every
+    /// other: only the rules between the pages of an update can be followed safely.
+    pub fn must_precede(&self, a: usize, b: usize) -> Option<Vec<usize>> {
+        let mut came_from: HashMap<usize, usize> = HashMap::new();
+        let mut queue = VecDeque::from([a]);
+
+        while let Some(page) = queue.pop_front() {
+            for &next in self.rules.get(&page).into_iter().flatten() {
+                if next == a || came_from.contains_key(&next) {
+                    continue;
+                }
+                came_from.insert(next, page);
+
+                if next == b {
+                    let mut chain = vec![b];
+                    while *chain.last().unwrap() != a {
+                        chain.push(came_from[chain.last().unwrap()]);
+                    }
+                    chain.reverse();
+                    return Some(chain);
+                }
+                queue.push_back(next);
+            }
+        }
+
+        None
+    }
+
+    /// All the pages the rules put after `page`, directly or not
+    pub fn after(&self, page: usize) -> HashSet<usize> {
+        let mut seen = HashSet::new();
+        let mut stack = vec![page];
+
+        while let Some(p) = stack.pop() {
+            for &next in self.rules.get(&p).into_iter().flatten() {
+                if seen.insert(next) {
+                    stack.push(next);
+                }
+            }
+        }
+
+        seen
+    }
+}
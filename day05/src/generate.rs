@@ -0,0 +1,46 @@
+//! Random ordering rules and updates. `size` is the number of updates.
+//!
+//! The pages follow a hidden order. When solvable, there's a rule for every pair of pages, so
+//! every update has exactly one right order. Otherwise some rules are missing or reversed.
+
+use aoc_common::generate::{Params, Rng};
+
+const PAGES: usize = 49;
+
+pub fn input(rng: &mut Rng, params: &Params) -> String {
+    let mut order = (10..=99).collect::<Vec<usize>>();
+    rng.shuffle(&mut order);
+    order.truncate(PAGES);
+
+    let mut text = String::new();
+
+    for (i, before) in order.iter().enumerate() {
+        for after in order[i + 1..].iter() {
+            if params.solvable || rng.chance(0.9) {
+                text.push_str(&format!("{before}|{after}\n"));
+            } else if rng.chance(0.5) {
+                text.push_str(&format!("{after}|{before}\n"));
+            }
+        }
+    }
+
+    text.push('\n');
+
+    for _ in 0..params.size {
+        let length = rng.between(2, 11) as usize * 2 + 1;
+        let mut positions = (0..PAGES).collect::<Vec<_>>();
+        rng.shuffle(&mut positions);
+        positions.truncate(length);
+
+        // About half of the updates come in the right order
+        if rng.chance(0.5) {
+            positions.sort();
+        }
+
+        let update = positions.iter().map(|&p| order[p].to_string()).collect::<Vec<_>>();
+        text.push_str(&update.join(","));
+        text.push('\n');
+    }
+
+    text
+}
@@ -0,0 +1,124 @@
+pub mod analysis;
+pub mod generate;
+pub mod topo;
+pub mod validate;
+
+use std::{collections::{HashMap, HashSet}, io::{BufReader, Read}};
+use aoc_common::{
+    error::{column_of, numbered_lines, parse_at, ParseError},
+    puzzle, Answer, ParseResult, Puzzle,
+};
+
+/// For every page, the pages that must come after it
+pub type Rules = HashMap<usize, HashSet<usize>>;
+pub type PageList = Vec<usize>;
+
+fn is_right(list: &[usize], rules: &Rules) -> bool {
+    let mut seen = HashSet::new();
+
+    for v in list {
+        if let Some(ruleset) = rules.get(v) {
+            if seen.iter().any(|k| ruleset.contains(k)) {
+                return false
+            }
+        }
+        seen.insert(*v);
+    }
+
+    true
+}
+
+/// A rule as written in the input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RuleLine {
+    pub line: usize,
+    pub before: usize,
+    pub after: usize,
+}
+
+/// Reads the rules as they are written, duplicates and all, and the updates
+pub fn read_input<R>(stream: BufReader<R>) -> ParseResult<(Vec<RuleLine>, Vec<PageList>)>
+    where R: Read,
+{
+    let mut rules = vec![];
+    let mut lists = vec![];
+    let mut collecting_lists = false;
+
+    for line in numbered_lines(stream) {
+        let (n, line) = line?;
+        let trimmed = line.trim();
+
+        if trimmed.is_empty() {
+            collecting_lists = true;
+        } else if collecting_lists {
+            lists.push(trimmed.split(',')
+                .map(|v| parse_at::<usize>(n, &line, v, "a page number"))
+                .collect::<ParseResult<Vec<_>>>()?);
+        } else {
+            let (a, b) = trimmed.split_once('|')
+                .ok_or_else(|| ParseError::new(n, column_of(&line, trimmed), "a rule like 'a|b'").found(trimmed))?;
+            let before = parse_at::<usize>(n, &line, a, "a page number")?;
+            let after = parse_at::<usize>(n, &line, b, "a page number")?;
+
+            rules.push(RuleLine { line: n, before, after });
+        }
+    }
+
+    Ok((rules, lists))
+}
+
+fn read_rules<R>(stream: BufReader<R>) -> ParseResult<(Rules, Vec<PageList>)>
+    where R: Read,
+{
+    let (lines, lists) = read_input(stream)?;
+    let mut rules = HashMap::new();
+
+    for rule in lines {
+        rules.entry(rule.before).or_insert_with(HashSet::new).insert(rule.after);
+    }
+
+    Ok((rules, lists))
+}
+
+fn partition_lists(lists: &[PageList], rules: &Rules) -> (Vec<PageList>, Vec<PageList>) {
+    lists.iter()
+        .cloned()
+        .partition(|list| is_right(list, rules))
+}
+
+pub struct Day05;
+
+impl Puzzle for Day05 {
+    type Input = (Rules, Vec<PageList>);
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        read_rules(BufReader::new(input.as_bytes()))
+    }
+
+    fn part1((rules, printing_lists): &Self::Input) -> Answer {
+        let (well_ordered, _) = partition_lists(printing_lists, rules);
+
+        well_ordered.iter()
+            .map(|list| list[list.len() / 2])
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part2((rules, printing_lists): &Self::Input) -> Answer {
+        let (_, badly_ordered) = partition_lists(printing_lists, rules);
+
+        // No answer if the rules of some update contradict each other
+        badly_ordered.iter()
+            .map(|list| topo::sort(list, rules).map(|order| order.pages[order.pages.len() / 2]))
+            .sum::<Result<usize, _>>()
+            .map_or(Answer::Unsolved, Answer::from)
+    }
+}
+
+pub fn part1(input: &str) -> ParseResult<Answer> {
+    puzzle::solve_part1::<Day05>(input)
+}
+
+pub fn part2(input: &str) -> ParseResult<Answer> {
+    puzzle::solve_part2::<Day05>(input)
+}
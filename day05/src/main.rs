@@ -1,87 +1,112 @@
-use std::{collections::{HashMap, HashSet}, io::{stdin, BufRead, BufReader, Read}};
+use std::{io::BufReader, process::exit};
 
-fn is_right(list: &[usize], rules: &HashMap<usize, HashSet<usize>>) -> bool {
-    let mut seen = HashSet::new();
+use aoc_common::{error::or_exit, input::for_day, report::report_if_requested, Puzzle};
+use day05::{analysis::{self, Closure}, read_input, topo, validate, Day05};
 
-    for v in list {
-        if let Some(ruleset) = rules.get(v) {
-            if seen.iter().any(|k| ruleset.contains(k)) {
-                return false
-            }
+/// The pages given with `flag` as `A,B,C`, if any
+fn pages_after(flag: &str) -> Option<Vec<usize>> {
+    let value = std::env::args().skip_while(|a| a != flag).nth(1)?;
+
+    match value.split(',').map(|p| p.trim().parse()).collect() {
+        Ok(pages) => Some(pages),
+        Err(_) => {
+            eprintln!("Expected page numbers like '75,47,61' after {flag}, not '{value}'");
+            exit(2);
         }
-        seen.insert(*v);
     }
-
-    true
 }
 
-fn fix_list(list: &[usize], rules: &HashMap<usize, HashSet<usize>>) -> Vec<usize> {
-    let mut result =  vec![];
-
-    result.push(list[0]);
-
-    for &k in &list[1..] {
-        let k_rules = rules.get(&k);
-        let mut insert_at: Option<usize> = None;
-
-        for (i, v) in result.iter().enumerate() {
-            if k_rules.is_some_and(|order| order.contains(v)) {
-                insert_at = Some(i);
-                break;
+fn main() {
+    let pages = pages_after("--sort");
+    let query = pages_after("--precedes");
+    let input = for_day(5);
+
+    if let Some(query) = query {
+        let [a, b] = query[..] else {
+            eprintln!("Expected two pages like '47,13' after --precedes");
+            exit(2);
+        };
+        let (rules, _) = or_exit(Day05::parse(&input));
+        let chain = Closure::new(&rules).must_precede(a, b);
+
+        match chain {
+            Some(chain) => {
+                let rules = chain.windows(2).map(|w| format!("{}|{}", w[0], w[1])).collect::<Vec<_>>();
+                println!("{a} must come before {b}: {}", rules.join(", "));
             }
+            None => println!("Nothing puts {a} before {b}"),
         }
+        return;
+    }
+
+    if std::env::args().any(|a| a == "--lint") {
+        let (rules, updates) = or_exit(read_input(BufReader::new(input.as_bytes())));
+        let findings = analysis::lint(&rules, &updates);
 
-        if let Some(index) = insert_at {
-            result.insert(index, k);
-        } else {
-            result.push(k);
+        for finding in findings.iter() {
+            println!("{finding}");
         }
+        if findings.is_empty() {
+            println!("Nothing odd about the {} rules", rules.len());
+        }
+        return;
     }
 
-    result
-}
+    if let Some(pages) = pages {
+        let (rules, _) = or_exit(Day05::parse(&input));
 
-fn read_rules<R>(stream: BufReader<R>) -> (HashMap<usize, HashSet<usize>>, Vec<Vec<usize>>)
-    where R: Read,
-{
-    let lines = stream.lines().into_iter();
-    let mut rules = HashMap::new();
-    let mut lists = vec![];
-    let mut collecting_lists = false;
-
-    for line in lines {
-        let line = line.unwrap();
-        if line.trim().is_empty() {
-            collecting_lists = true;
-        } else if collecting_lists {
-            lists.push(line.trim().split(',').map(|v| v.parse::<usize>().unwrap()).collect());
-        } else {
-            let bits = line.trim().split('|').collect::<Vec<_>>();
-            let (a, b) = (bits[0].parse::<usize>().unwrap(), bits[1].parse::<usize>().unwrap());
-
-            rules.entry(a).or_insert(HashSet::new()).insert(b);
+        match topo::sort(&pages, &rules) {
+            Ok(order) => {
+                let pages = order.pages.iter().map(|p| p.to_string()).collect::<Vec<_>>();
+                println!("{}", pages.join(","));
+                if let Some((a, b)) = order.ambiguity {
+                    println!("Not the only order: nothing decides between {a} and {b}");
+                }
+            }
+            Err(cycle) => {
+                println!("No possible order, the rules go round in circles: {cycle}");
+                exit(1);
+            }
         }
+        return;
     }
 
-    (rules, lists)
-}
-
-fn main() {
-    let (rules, printing_lists) = read_rules(BufReader::new(stdin()));
+    if std::env::args().any(|a| a == "--explain") {
+        let (rules, updates) = or_exit(Day05::parse(&input));
+        let list = |pages: &[usize]| pages.iter().map(|p| p.to_string()).collect::<Vec<_>>().join(",");
 
-    let (well_ordered, badly_ordered): (Vec<_>, Vec<_>) = printing_lists.into_iter()
-        .partition(|list| is_right(list, &rules));
+        for (n, update) in updates.iter().enumerate() {
+            let broken = validate::violations(update, &rules);
+            if broken.is_empty() {
+                continue;
+            }
 
-    let middle_page_sum = well_ordered.iter()
-        .map(|list| list[list.len() / 2])
-        .sum::<usize>();
+            println!("Update {}: {}", n + 1, list(update));
+            for v in broken {
+                println!("  breaks {}|{}: {} is #{}, {} is #{}", v.before, v.after,
+                    v.before, v.positions.0 + 1, v.after, v.positions.1 + 1);
+            }
 
-    println!("Sum of ordered lists: {middle_page_sum}");
+            match validate::fix(update, &rules) {
+                Ok(fix) => {
+                    for m in fix.moves.iter() {
+                        match m.to {
+                            0 => println!("  move {} to the front", m.page),
+                            to => println!("  move {} after {}", m.page, fix.order[to - 1]),
+                        }
+                    }
+                    println!("  fixed: {}", list(&fix.order));
+                }
+                Err(cycle) => println!("  can't be fixed, the rules go round in circles: {cycle}"),
+            }
+        }
+        return;
+    }
 
-    let middle_page_sum = badly_ordered.iter()
-        .map(|list| fix_list(list, &rules))
-        .map(|list| list[list.len() / 2])
-        .sum::<usize>();
+    if report_if_requested(5, &input, day05::part1, day05::part2) {
+        return;
+    }
 
-    println!("Sum of reordered lists: {middle_page_sum}");
+    println!("Sum of ordered lists: {}", or_exit(day05::part1(&input)));
+    println!("Sum of reordered lists: {}", or_exit(day05::part2(&input)));
 }
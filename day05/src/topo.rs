@@ -0,0 +1,113 @@
+//! Putting pages in an order that follows the rules.
+//!
+//! Only the rules between the pages being sorted count: the rules of the whole puzzle have
+//! cycles, but the rules between the pages of any one update don't.
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+
+use crate::Rules;
+
+/// Pages in an order following all the rules between them
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Order {
+    pub pages: Vec<usize>,
+    /// Two pages that could have been swapped, if the rules allow more than one order. They
+    /// were both free to go at the same point.
+    pub ambiguity: Option<(usize, usize)>,
+}
+
+impl Order {
+    pub fn is_unique(&self) -> bool {
+        self.ambiguity.is_none()
+    }
+}
+
+/// Pages that must all come before each other, following the rules `a|b`, `b|c`... back to
+/// the first one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle {
+    pub pages: Vec<usize>,
+}
+
+impl Cycle {
+    /// The rules making up the cycle, as `(before, after)` pairs
+    pub fn rules(&self) -> Vec<(usize, usize)> {
+        (0..self.pages.len())
+            .map(|i| (self.pages[i], self.pages[(i + 1) % self.pages.len()]))
+            .collect()
+    }
+}
+
+impl Display for Cycle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rules = self.rules().iter().map(|(a, b)| format!("{a}|{b}")).collect::<Vec<_>>();
+
+        write!(f, "{}", rules.join(", "))
+    }
+}
+
+/// Sorts the pages following the rules between them. When several pages are free to go next,
+/// the one coming first in `pages` goes first, so pages already in order stay in place.
+/// Repeated pages are all kept, side by side where the first of them goes.
+pub fn sort(pages: &[usize], rules: &Rules) -> Result<Order, Cycle> {
+    let mut distinct = vec![];
+    for &p in pages {
+        if !distinct.contains(&p) {
+            distinct.push(p);
+        }
+    }
+
+    let subset = distinct.iter().copied().collect::<HashSet<_>>();
+    let after = |p: usize| rules.get(&p).into_iter().flatten().copied().filter(|q| subset.contains(q));
+
+    let mut waiting_for: HashMap<usize, usize> = distinct.iter().map(|&p| (p, 0)).collect();
+    for &p in &distinct {
+        for q in after(p) {
+            *waiting_for.get_mut(&q).unwrap() += 1;
+        }
+    }
+
+    let mut order = Order { pages: vec![], ambiguity: None };
+    let mut placed = HashSet::new();
+
+    while placed.len() < distinct.len() {
+        let mut free = distinct.iter().copied().filter(|p| !placed.contains(p) && waiting_for[p] == 0);
+
+        let Some(next) = free.next() else {
+            return Err(find_cycle(&distinct, &placed, rules));
+        };
+        if let (None, Some(other)) = (order.ambiguity, free.next()) {
+            order.ambiguity = Some((next, other));
+        }
+
+        for q in after(next) {
+            *waiting_for.get_mut(&q).unwrap() -= 1;
+        }
+        placed.insert(next);
+        order.pages.extend(pages.iter().filter(|&&p| p == next));
+    }
+
+    Ok(order)
+}
+
+// Every page left over has a rule putting another leftover page before it. Going back from
+// one to the other necessarily ends up in a loop.
+fn find_cycle(pages: &[usize], placed: &HashSet<usize>, rules: &Rules) -> Cycle {
+    let left = pages.iter().copied().filter(|p| !placed.contains(p)).collect::<Vec<_>>();
+    let before = |p: usize| *left.iter().find(|&&q| rules.get(&q).is_some_and(|r| r.contains(&p))).unwrap();
+
+    let mut path = vec![left[0]];
+    loop {
+        let previous = before(*path.last().unwrap());
+
+        if let Some(start) = path.iter().position(|&p| p == previous) {
+            let mut pages = path[start..].to_vec();
+            pages.reverse();
+            return Cycle { pages };
+        }
+        path.push(previous);
+    }
+}
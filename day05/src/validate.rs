@@ -0,0 +1,169 @@
+//! Why an update is out of order, and how to fix it with as few moves as possible.
+//!
+//! Positions start at 0. Pages are expected to appear only once per update, as in the puzzle.
+
+use crate::{topo::{self, Cycle}, Rules};
+
+/// A rule `before|after` broken by an update
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Violation {
+    pub before: usize,
+    pub after: usize,
+    /// Positions of `before` and `after` in the update, the first one being the larger
+    pub positions: (usize, usize),
+}
+
+/// A page to take out of the update and put back elsewhere
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub page: usize,
+    /// Position in the update as it is
+    pub from: usize,
+    /// Position once fixed
+    pub to: usize,
+}
+
+/// An order following the rules, reached by moving as few pages as possible. The pages not
+/// moved keep their order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fix {
+    pub order: Vec<usize>,
+    pub moves: Vec<Move>,
+}
+
+/// Every rule broken by the update, in the order of the pages that should come first
+pub fn violations(update: &[usize], rules: &Rules) -> Vec<Violation> {
+    let mut found = vec![];
+
+    for (i, &before) in update.iter().enumerate() {
+        for (j, &after) in update[..i].iter().enumerate() {
+            if rules.get(&before).is_some_and(|r| r.contains(&after)) {
+                found.push(Violation { before, after, positions: (i, j) });
+            }
+        }
+    }
+
+    found
+}
+
+// must[i][j]: the rules put the page at i before the one at j, directly or through other pages
+// of the update
+fn closure(update: &[usize], rules: &Rules) -> Vec<Vec<bool>> {
+    let n = update.len();
+    let mut must = (0..n)
+        .map(|i| (0..n).map(|j| rules.get(&update[i]).is_some_and(|r| r.contains(&update[j]))).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    for k in 0..n {
+        let through = must[k].clone();
+
+        for row in must.iter_mut().filter(|row| row[k]) {
+            for (cell, &via) in row.iter_mut().zip(&through) {
+                *cell |= via;
+            }
+        }
+    }
+
+    must
+}
+
+/// Finds an order following the rules, and the fewest pages to move to get there. Fails if the
+/// rules between the pages of the update have a cycle.
+pub fn fix(update: &[usize], rules: &Rules) -> Result<Fix, Cycle> {
+    topo::sort(update, rules)?;
+
+    let n = update.len();
+    let must = closure(update, rules);
+
+    // Two pages can both stay where they are unless they're the wrong way round, directly or
+    // not. Being the wrong way round is a partial order between positions, so the largest
+    // set of pages that can stay is one of its largest antichains: as many as there are
+    // pages, minus a maximum matching of the order (Dilworth, through König's theorem).
+    let wrong = |i: usize, j: usize| i < j && must[j][i];
+    let mut matched_to: Vec<Option<usize>> = vec![None; n];
+
+    for i in 0..n {
+        let mut seen = vec![false; n];
+        augment(i, &wrong, &mut matched_to, &mut seen);
+    }
+
+    // The minimum vertex cover is made of the left sides not reachable from an unmatched left
+    // side through alternating paths, and of the right sides that are
+    let matched_from = (0..n).map(|i| matched_to.contains(&Some(i))).collect::<Vec<_>>();
+    let mut left_reached = vec![false; n];
+    let mut right_reached = vec![false; n];
+    let mut stack = (0..n).filter(|&i| !matched_from[i]).collect::<Vec<_>>();
+
+    while let Some(i) = stack.pop() {
+        if left_reached[i] {
+            continue;
+        }
+        left_reached[i] = true;
+
+        for j in 0..n {
+            if !wrong(i, j) || right_reached[j] {
+                continue;
+            }
+            right_reached[j] = true;
+            if let Some(k) = matched_to[j] {
+                stack.push(k);
+            }
+        }
+    }
+
+    let stays = (0..n).map(|i| left_reached[i] && !right_reached[i]).collect::<Vec<_>>();
+
+    // Sorting the positions, the pages staying keep their relative order
+    let staying = (0..n).filter(|&i| stays[i]).collect::<Vec<_>>();
+    let mut waiting_for = vec![0; n];
+    let mut next: Vec<Vec<usize>> = vec![vec![]; n];
+    for i in 0..n {
+        for j in 0..n {
+            if must[i][j] && i != j {
+                next[i].push(j);
+                waiting_for[j] += 1;
+            }
+        }
+    }
+    for pair in staying.windows(2) {
+        next[pair[0]].push(pair[1]);
+        waiting_for[pair[1]] += 1;
+    }
+
+    let mut placed = vec![false; n];
+    let mut positions = vec![];
+    while let Some(i) = (0..n).find(|&i| !placed[i] && waiting_for[i] == 0) {
+        placed[i] = true;
+        positions.push(i);
+        for &j in &next[i] {
+            waiting_for[j] -= 1;
+        }
+    }
+
+    let moves = positions.iter()
+        .enumerate()
+        .filter(|&(_, &from)| !stays[from])
+        .map(|(to, &from)| Move { page: update[from], from, to })
+        .collect();
+
+    Ok(Fix { order: positions.iter().map(|&i| update[i]).collect(), moves })
+}
+
+// Kuhn's augmenting path search for the bipartite matching of positions
+fn augment<F>(i: usize, wrong: &F, matched_to: &mut [Option<usize>], seen: &mut [bool]) -> bool
+where
+    F: Fn(usize, usize) -> bool,
+{
+    for j in 0..matched_to.len() {
+        if wrong(i, j) && !seen[j] {
+            seen[j] = true;
+
+            if matched_to[j].is_none_or(|k| augment(k, wrong, matched_to, seen)) {
+                matched_to[j] = Some(i);
+                return true;
+            }
+        }
+    }
+
+    false
+}
@@ -0,0 +1,42 @@
+use std::io::BufReader;
+
+use aoc_common::Puzzle;
+use day05::{analysis::{lint, Closure, Finding}, read_input, Day05};
+
+#[test]
+fn finds_odd_rules() {
+    let input = "1|2\n2|3\n1|2\n3|2\n4|4\n1|2\n\n1,2,3\n5,1,6,5\n7,5\n";
+    let (rules, updates) = read_input(BufReader::new(input.as_bytes())).unwrap();
+
+    assert_eq!(lint(&rules, &updates), [
+        Finding::Duplicate { before: 1, after: 2, lines: vec![1, 3, 6] },
+        Finding::Contradiction { before: 2, after: 3, lines: (2, 4) },
+        Finding::SelfReference { page: 4, line: 5 },
+        Finding::Unruled { page: 5, updates: vec![2, 3] },
+        Finding::Unruled { page: 6, updates: vec![2] },
+        Finding::Unruled { page: 7, updates: vec![3] },
+    ]);
+
+    assert_eq!(Finding::Contradiction { before: 2, after: 3, lines: (2, 4) }.to_string(),
+        "2|3 on line 2 contradicts 3|2 on line 4");
+}
+
+#[test]
+fn nothing_odd_in_the_example() {
+    let input = std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/example1.txt")).unwrap();
+    let (rules, updates) = read_input(BufReader::new(input.as_bytes())).unwrap();
+
+    assert!(lint(&rules, &updates).is_empty());
+}
+
+#[test]
+fn follows_rules_transitively() {
+    let (rules, _) = Day05::parse("1|2\n2|3\n3|4\n1|5\n5|4\n6|1\n\n1\n").unwrap();
+    let closure = Closure::new(&rules);
+
+    assert_eq!(closure.must_precede(1, 2), Some(vec![1, 2]));
+    assert_eq!(closure.must_precede(6, 4).map(|chain| chain.len()), Some(4));
+    assert_eq!(closure.must_precede(4, 1), None);
+    assert_eq!(closure.must_precede(2, 5), None);
+    assert_eq!(closure.after(1), [2, 3, 4, 5].into_iter().collect());
+}
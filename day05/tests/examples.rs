@@ -0,0 +1,4 @@
+#[test]
+fn examples() {
+    aoc_common::testing::check_fixtures(env!("CARGO_MANIFEST_DIR"), day05::part1, day05::part2);
+}
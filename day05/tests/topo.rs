@@ -0,0 +1,51 @@
+use aoc_common::Puzzle;
+use day05::{topo::{sort, Cycle}, Day05, Rules};
+
+fn rules(text: &str) -> Rules {
+    Day05::parse(&format!("{text}\n\n1\n")).unwrap().0
+}
+
+#[test]
+fn sorts_the_example_updates() {
+    let input = std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/example1.txt")).unwrap();
+    let (rules, _) = Day05::parse(&input).unwrap();
+
+    let order = sort(&[61, 13, 29], &rules).unwrap();
+    assert_eq!(order.pages, [61, 29, 13]);
+    assert!(order.is_unique());
+
+    assert_eq!(sort(&[97, 13, 75, 29, 47], &rules).unwrap().pages, [97, 75, 47, 29, 13]);
+}
+
+#[test]
+fn flags_ambiguous_orders() {
+    let rules = rules("1|2\n1|3");
+
+    let order = sort(&[3, 2, 1], &rules).unwrap();
+    assert_eq!(order.pages, [1, 3, 2]);
+    assert_eq!(order.ambiguity, Some((3, 2)));
+
+    // Pages without rules keep their place, repeated ones next to each other
+    assert_eq!(sort(&[5, 4, 5], &rules).unwrap().pages, [5, 5, 4]);
+    assert_eq!(sort(&[2, 1, 2], &rules).unwrap().pages, [1, 2, 2]);
+}
+
+#[test]
+fn reports_cycles() {
+    let rules = rules("1|2\n2|3\n3|4\n4|2\n5|1");
+
+    let cycle = sort(&[5, 4, 3, 2, 1], &rules).unwrap_err();
+    assert_eq!(cycle.pages.len(), 3);
+    for (a, b) in cycle.rules() {
+        assert!(rules[&a].contains(&b), "{a}|{b} isn't a rule");
+    }
+    assert_eq!(Cycle { pages: vec![2, 3, 4] }.to_string(), "2|3, 3|4, 4|2");
+
+    // Without one of its pages, the cycle is gone
+    assert_eq!(sort(&[5, 4, 2, 1], &rules).unwrap().pages, [5, 4, 1, 2]);
+}
+
+#[test]
+fn no_answer_with_cycles() {
+    assert_eq!(day05::part2("1|2\n2|1\n\n1,2\n").unwrap(), aoc_common::Answer::Unsolved);
+}
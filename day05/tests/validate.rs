@@ -0,0 +1,106 @@
+use aoc_common::{generate::Rng, Puzzle};
+use day05::{validate::{fix, violations, Move, Violation}, Day05, Rules};
+
+fn example() -> (Rules, Vec<Vec<usize>>) {
+    let input = std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/example1.txt")).unwrap();
+    Day05::parse(&input).unwrap()
+}
+
+#[test]
+fn lists_broken_rules() {
+    let (rules, updates) = example();
+
+    assert!(violations(&updates[0], &rules).is_empty());
+    assert_eq!(violations(&[75, 97, 47, 61, 53], &rules), [
+        Violation { before: 97, after: 75, positions: (1, 0) },
+    ]);
+    assert_eq!(violations(&[97, 13, 75, 29, 47], &rules).len(), 4);
+}
+
+#[test]
+fn fixes_with_few_moves() {
+    let (rules, _) = example();
+
+    let fixed = fix(&[75, 97, 47, 61, 53], &rules).unwrap();
+    assert_eq!(fixed.order, [97, 75, 47, 61, 53]);
+    assert_eq!(fixed.moves.len(), 1);
+
+    let fixed = fix(&[61, 13, 29], &rules).unwrap();
+    assert_eq!(fixed.order, [61, 29, 13]);
+    assert_eq!(fixed.moves.len(), 1);
+
+    let fixed = fix(&[97, 13, 75, 29, 47], &rules).unwrap();
+    assert_eq!(fixed.order, [97, 75, 47, 29, 13]);
+    // 97, 75 and 47 stay in place, the other two go around them
+    assert_eq!(fixed.moves, [Move { page: 29, from: 3, to: 3 }, Move { page: 13, from: 1, to: 4 }]);
+
+    let (cycle, _) = Day05::parse("1|2\n2|3\n3|1\n\n1\n").unwrap();
+    assert!(fix(&[3, 2, 1], &cycle).is_err());
+}
+
+fn permutations(pages: &[usize]) -> Vec<Vec<usize>> {
+    if pages.len() <= 1 {
+        return vec![pages.to_vec()];
+    }
+
+    (0..pages.len())
+        .flat_map(|i| {
+            let mut rest = pages.to_vec();
+            let first = rest.remove(i);
+            permutations(&rest).into_iter().map(move |mut p| { p.insert(0, first); p })
+        })
+        .collect()
+}
+
+// Pages that can stay: the longest subsequence of the update in the same order in `order`
+fn kept(update: &[usize], order: &[usize]) -> usize {
+    let ranks = update.iter().map(|p| order.iter().position(|q| q == p).unwrap()).collect::<Vec<_>>();
+    let mut longest = vec![1; ranks.len()];
+
+    for i in 0..ranks.len() {
+        for j in 0..i {
+            if ranks[j] < ranks[i] {
+                longest[i] = longest[i].max(longest[j] + 1);
+            }
+        }
+    }
+
+    longest.into_iter().max().unwrap_or(0)
+}
+
+#[test]
+fn moves_as_few_pages_as_possible() {
+    let mut rng = Rng::new(5);
+
+    for _ in 0..300 {
+        let n = rng.between(1, 6) as usize;
+        let mut hidden = (1..=n).collect::<Vec<_>>();
+        rng.shuffle(&mut hidden);
+
+        let mut rules = Rules::new();
+        for i in 0..n {
+            for j in i + 1..n {
+                if rng.chance(0.4) {
+                    rules.entry(hidden[i]).or_default().insert(hidden[j]);
+                }
+            }
+        }
+
+        let mut update = (1..=n).collect::<Vec<_>>();
+        rng.shuffle(&mut update);
+
+        let fixed = fix(&update, &rules).unwrap();
+        assert!(violations(&fixed.order, &rules).is_empty(), "{update:?} fixed as {:?}", fixed.order);
+
+        let best = permutations(&update).iter()
+            .filter(|p| violations(p, &rules).is_empty())
+            .map(|p| n - kept(&update, p))
+            .min()
+            .unwrap();
+        assert_eq!(fixed.moves.len(), best, "{update:?} with {rules:?}");
+
+        for m in fixed.moves.iter() {
+            assert_eq!((update[m.from], fixed.order[m.to]), (m.page, m.page));
+        }
+    }
+}
@@ -0,0 +1,64 @@
+//! Random lab maps with a guard. `size` is the side of the map.
+//!
+//! When solvable, the guard leaves the map in the end instead of walking in circles.
+
+use std::collections::HashSet;
+
+use aoc_common::{
+    generate::{Params, Rng},
+    Direction, Grid, Point,
+};
+
+use crate::{GuardMotion, Map};
+
+// Same walk as find_unique_positions(), only telling whether it ends out of the map
+fn leaves(map: &Map) -> bool {
+    let mut guard = map.guard.clone();
+    let mut turns = HashSet::new();
+
+    loop {
+        let next_coords = guard.forward();
+
+        if map.out_of_bounds(&next_coords) {
+            return true;
+        } else if map.hit_obstacle(&next_coords) {
+            if !turns.insert(guard.clone()) {
+                return false;
+            }
+            guard.turn_right();
+        } else {
+            guard.coords = next_coords;
+        }
+    }
+}
+
+pub fn input(rng: &mut Rng, params: &Params) -> String {
+    let side = params.size.max(2);
+
+    loop {
+        let obstacles = Grid::new(side, side, false).map(|_| rng.chance(0.05));
+        let free = obstacles.positions(|&o| !o).collect::<Vec<Point>>();
+        if free.is_empty() {
+            continue;
+        }
+
+        let guard = GuardMotion { coords: *rng.pick(&free), direction: Direction::Up };
+        let map = Map { obstacles, guard };
+
+        if !params.solvable || leaves(&map) {
+            let mut text = String::new();
+            for (p, &obstacle) in map.obstacles.iter() {
+                text.push(match obstacle {
+                    true => '#',
+                    false if p == map.guard.coords => '^',
+                    false => '.',
+                });
+                if p.x as usize == side - 1 {
+                    text.push('\n');
+                }
+            }
+
+            return text;
+        }
+    }
+}
@@ -0,0 +1,183 @@
+pub mod generate;
+mod obstruction;
+
+use std::{
+    collections::HashSet,
+    io::{BufReader, Read}
+};
+use aoc_common::{
+    puzzle,
+    render::{arrow, Animation, Canvas, Cell, Rgb, Sampler},
+    Answer, Direction, Grid, ParseError, ParseResult, Point, Puzzle,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct GuardMotion {
+    coords: Point,
+    direction: Direction,
+}
+
+impl GuardMotion {
+    fn turn_right(&mut self) {
+        self.direction = self.direction.turn_right();
+    }
+
+    fn forward(&self) -> Point {
+        self.coords.step(self.direction)
+    }
+}
+
+#[derive(Debug)]
+pub struct Map {
+    obstacles: Grid<bool>,
+    guard: GuardMotion,
+}
+
+impl Map {
+    fn out_of_bounds(&self, coords: &Point) -> bool {
+        !self.obstacles.contains(*coords)
+    }
+
+    fn hit_obstacle(&self, coords: &Point) -> bool {
+        self.obstacles.get(*coords).is_some_and(|&obstacle| obstacle)
+    }
+
+    fn canvas(&self, walked: &HashSet<Point>, guard: &GuardMotion) -> Canvas {
+        let mut canvas = Canvas::from_grid(&self.obstacles, |&obstacle| match obstacle {
+            true => Cell::new('#', Rgb::WHITE),
+            false => Cell::EMPTY,
+        });
+
+        canvas.mark(walked, Cell::new('X', Rgb::YELLOW));
+        canvas.draw(guard.coords, Cell::new(arrow(guard.direction), Rgb::RED));
+        canvas
+    }
+}
+
+fn find_unique_positions(map: &Map) -> usize {
+    let initial = &map.guard;
+    let mut current_guard = initial.clone();
+
+    let mut seen = HashSet::new();
+    let mut exit_positions = HashSet::new();
+
+    seen.insert(current_guard.coords);
+    exit_positions.insert(initial.clone());
+
+    loop {
+        let next_coords = current_guard.forward();
+
+        if map.out_of_bounds(&next_coords) {
+            break;
+        } else if map.hit_obstacle(&next_coords) {
+            current_guard.turn_right();
+
+            // Turning the same way at the same spot twice, the guard is going round in circles
+            if !exit_positions.insert(current_guard.clone()) {
+                break;
+            }
+        } else {
+            seen.insert(next_coords);
+            current_guard.coords = next_coords;
+
+            if exit_positions.contains(&current_guard) {
+                break;
+            }
+        }
+    }
+
+    seen.len()
+}
+
+const STEPS_PER_FRAME: usize = 20;
+
+// Same walk as find_unique_positions, drawing the visited cells as it goes
+fn trace_walk(map: &Map) -> Animation {
+    let mut current_guard = map.guard.clone();
+    let mut seen = HashSet::from([current_guard.coords]);
+    let mut exit_positions = HashSet::from([current_guard.clone()]);
+    let mut sampler = Sampler::new(STEPS_PER_FRAME);
+
+    loop {
+        sampler.offer(|| map.canvas(&seen, &current_guard));
+        let next_coords = current_guard.forward();
+
+        if map.out_of_bounds(&next_coords) {
+            break;
+        } else if map.hit_obstacle(&next_coords) {
+            current_guard.turn_right();
+
+            // Turning the same way at the same spot twice, the guard is going round in circles
+            if !exit_positions.insert(current_guard.clone()) {
+                break;
+            }
+        } else {
+            seen.insert(next_coords);
+            current_guard.coords = next_coords;
+
+            if exit_positions.contains(&current_guard) {
+                break;
+            }
+        }
+    }
+
+    sampler.keep(map.canvas(&seen, &current_guard));
+    sampler.finish()
+}
+
+fn read_map<R>(stream: BufReader<R>) -> ParseResult<Map>
+    where R: Read,
+{
+    let raw_map = Grid::parse(stream, |c| match c {
+        '.' | '#' | '^' | 'v' | '<' | '>' => Ok(c),
+        _ => Err("'.', '#' or a guard ('^', 'v', '<', '>')".to_string()),
+    })?;
+
+    let guard = raw_map.iter()
+        .find_map(|(coords, c)| {
+            let direction = match c {
+                '^' => Direction::Up,
+                'v' => Direction::Down,
+                '<' => Direction::Left,
+                '>' => Direction::Right,
+                _ => return None
+            };
+            Some(GuardMotion { coords, direction })
+        })
+        .ok_or_else(|| ParseError::new(raw_map.height() + 1, 1, "a guard somewhere in the map").found(""))?;
+
+    Ok(Map {
+        obstacles: raw_map.map(|&c| c == '#'),
+        guard,
+    })
+}
+
+pub struct Day06;
+
+impl Puzzle for Day06 {
+    type Input = Map;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        read_map(BufReader::new(input.as_bytes()))
+    }
+
+    fn part1(map: &Self::Input) -> Answer {
+        find_unique_positions(map).into()
+    }
+
+    fn part2(map: &Self::Input) -> Answer {
+        obstruction::count_loop_options(map).into()
+    }
+
+    fn render(map: &Self::Input) -> Option<Animation> {
+        Some(trace_walk(map))
+    }
+}
+
+pub fn part1(input: &str) -> ParseResult<Answer> {
+    puzzle::solve_part1::<Day06>(input)
+}
+
+pub fn part2(input: &str) -> ParseResult<Answer> {
+    puzzle::solve_part2::<Day06>(input)
+}
@@ -0,0 +1,164 @@
+//! Where to put a new obstacle to trap the guard in a loop.
+//!
+//! An obstacle off the guard's patrol changes nothing, so only the cells of the patrol are
+//! tried. The patrol is the same up to the first time the guard would walk into the new
+//! obstacle, so the simulation starts right there. From then on, the guard jumps from
+//! obstacle to obstacle with precomputed tables, and the states where it turns are kept in a
+//! bitset to find out when it goes round in circles.
+
+use aoc_common::{Direction, Grid, Point};
+
+use crate::{GuardMotion, Map};
+
+fn index_of(direction: Direction) -> usize {
+    match direction {
+        Direction::Up => 0,
+        Direction::Right => 1,
+        Direction::Down => 2,
+        Direction::Left => 3,
+    }
+}
+
+/// One bit per cell and direction
+struct StateSet {
+    width: usize,
+    words: Vec<u64>,
+}
+
+impl StateSet {
+    fn new(width: usize, height: usize) -> Self {
+        StateSet { width, words: vec![0; (width * height * 4).div_ceil(64)] }
+    }
+
+    /// Adds the state, telling whether it's new
+    fn insert(&mut self, guard: &GuardMotion) -> bool {
+        let bit = ((guard.coords.y as usize * self.width + guard.coords.x as usize) * 4) + index_of(guard.direction);
+        let (word, mask) = (bit / 64, 1u64 << (bit % 64));
+
+        let new = self.words[word] & mask == 0;
+        self.words[word] |= mask;
+        new
+    }
+
+    fn clear(&mut self) {
+        self.words.fill(0);
+    }
+}
+
+/// For every cell and direction, the last cell before the next obstacle, or `None` if the
+/// guard leaves the map on the way
+struct Jumps {
+    stops: Grid<[Option<Point>; 4]>,
+}
+
+impl Jumps {
+    fn new(map: &Map) -> Self {
+        let obstacles = &map.obstacles;
+        let mut stops = Grid::new(obstacles.width(), obstacles.height(), [None; 4]);
+
+        for direction in Direction::ALL {
+            // Going backwards from the edge the guard walks towards, every cell stops where the
+            // cell ahead of it stops, unless the cell ahead is an obstacle
+            let (dx, dy) = direction.delta();
+            let mut points = obstacles.points().collect::<Vec<_>>();
+            if dx > 0 || dy > 0 {
+                points.reverse();
+            }
+
+            for p in points {
+                let ahead = p.step(direction);
+                stops[p][index_of(direction)] = match obstacles.get(ahead) {
+                    None => None,
+                    Some(true) => Some(p),
+                    Some(false) => stops[ahead][index_of(direction)],
+                };
+            }
+        }
+
+        Jumps { stops }
+    }
+
+    /// Where the guard stops walking, if it does, with an extra obstacle at `extra`
+    fn stop(&self, guard: &GuardMotion, extra: Point) -> Option<Point> {
+        let from = guard.coords;
+        let stop = self.stops[from][index_of(guard.direction)];
+
+        let ahead = match guard.direction {
+            Direction::Up => extra.x == from.x && extra.y < from.y && stop.is_none_or(|s| extra.y >= s.y),
+            Direction::Down => extra.x == from.x && extra.y > from.y && stop.is_none_or(|s| extra.y <= s.y),
+            Direction::Left => extra.y == from.y && extra.x < from.x && stop.is_none_or(|s| extra.x >= s.x),
+            Direction::Right => extra.y == from.y && extra.x > from.x && stop.is_none_or(|s| extra.x <= s.x),
+        };
+
+        if ahead {
+            Some(extra.step(guard.direction.opposite()))
+        } else {
+            stop
+        }
+    }
+}
+
+/// The cells of the patrol other than the start, each with the state of the guard just before
+/// it first walks into it, and whether the guard leaves the map in the end
+fn patrol(map: &Map, turns: &mut StateSet) -> (Vec<(Point, GuardMotion)>, bool) {
+    let mut visited = map.obstacles.map(|_| false);
+    let mut guard = map.guard.clone();
+    let mut cells = vec![];
+
+    visited[guard.coords] = true;
+
+    loop {
+        let next = guard.forward();
+
+        match map.obstacles.get(next) {
+            None => return (cells, true),
+            Some(true) => {
+                if !turns.insert(&guard) {
+                    return (cells, false);
+                }
+                guard.turn_right();
+            }
+            Some(false) => {
+                if !visited[next] {
+                    visited[next] = true;
+                    cells.push((next, guard.clone()));
+                }
+                guard.coords = next;
+            }
+        }
+    }
+}
+
+fn loops(mut guard: GuardMotion, extra: Point, jumps: &Jumps, turns: &mut StateSet) -> bool {
+    turns.clear();
+
+    while let Some(stop) = jumps.stop(&guard, extra) {
+        guard.coords = stop;
+        if !turns.insert(&guard) {
+            return true;
+        }
+        guard.turn_right();
+    }
+
+    false
+}
+
+pub(crate) fn count_loop_options(map: &Map) -> usize {
+    let mut turns = StateSet::new(map.obstacles.width(), map.obstacles.height());
+    let (cells, leaves) = patrol(map, &mut turns);
+    let jumps = Jumps::new(map);
+
+    let on_patrol = cells.iter()
+        .filter(|(cell, before)| loops(before.clone(), *cell, &jumps, &mut turns))
+        .count();
+
+    // If the guard is walking in circles already, an obstacle anywhere else keeps it that way
+    let elsewhere = if leaves {
+        0
+    } else {
+        let free = map.obstacles.iter().filter(|(_, &obstacle)| !obstacle).count();
+        free - 1 - cells.len()
+    };
+
+    on_patrol + elsewhere
+}
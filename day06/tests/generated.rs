@@ -0,0 +1,15 @@
+use aoc_common::{
+    generate::{Params, Rng},
+    Answer,
+};
+
+#[test]
+fn generated_maps_are_valid() {
+    for seed in 0..10 {
+        let params = Params { size: 20, solvable: true };
+        let input = day06::generate::input(&mut Rng::new(seed), &params);
+
+        assert_eq!(input, day06::generate::input(&mut Rng::new(seed), &params));
+        assert!(matches!(day06::part1(&input), Ok(Answer::Number(n)) if n > 0), "seed {seed}:\n{input}");
+    }
+}
@@ -0,0 +1,76 @@
+use std::collections::HashSet;
+
+use aoc_common::{
+    generate::{Params, Rng},
+    Answer,
+};
+
+// The guard's walk on the map as text, with an extra obstacle, telling whether it never ends
+fn walks_in_circles(rows: &[Vec<u8>], start: (i32, i32), extra: (i32, i32)) -> bool {
+    let (height, width) = (rows.len() as i32, rows[0].len() as i32);
+    let (mut x, mut y) = start;
+    let (mut dx, mut dy) = (0, -1);
+    let mut states = HashSet::new();
+
+    loop {
+        if !states.insert((x, y, dx, dy)) {
+            return true;
+        }
+
+        let (nx, ny) = (x + dx, y + dy);
+        if nx < 0 || ny < 0 || nx >= width || ny >= height {
+            return false;
+        }
+
+        if rows[ny as usize][nx as usize] == b'#' || (nx, ny) == extra {
+            (dx, dy) = (-dy, dx);
+        } else {
+            (x, y) = (nx, ny);
+        }
+    }
+}
+
+// Tries an obstacle on every free cell of the map, the slow way
+fn reference(input: &str) -> usize {
+    let rows = input.lines().map(|l| l.as_bytes().to_vec()).collect::<Vec<_>>();
+    let start = rows.iter()
+        .enumerate()
+        .find_map(|(y, row)| row.iter().position(|&c| c == b'^').map(|x| (x as i32, y as i32)))
+        .unwrap();
+
+    let mut options = 0;
+    for (y, row) in rows.iter().enumerate() {
+        for (x, &c) in row.iter().enumerate() {
+            if c == b'.' && walks_in_circles(&rows, start, (x as i32, y as i32)) {
+                options += 1;
+            }
+        }
+    }
+
+    options
+}
+
+#[test]
+fn same_options_as_trying_every_cell() {
+    for seed in 0..40 {
+        let params = Params { size: 8 + seed as usize % 17, solvable: seed % 4 != 0 };
+        let input = day06::generate::input(&mut Rng::new(seed), &params);
+
+        assert_eq!(day06::part2(&input).unwrap(), Answer::from(reference(&input)), "seed {seed}:\n{input}");
+    }
+}
+
+#[test]
+fn guard_already_walking_in_circles() {
+    let input = "\
+.#....
+.....#
+......
+#^....
+....#.
+";
+    let rows = input.lines().map(|l| l.as_bytes().to_vec()).collect::<Vec<_>>();
+    assert!(walks_in_circles(&rows, (1, 3), (-1, -1)));
+
+    assert_eq!(day06::part2(input).unwrap(), Answer::from(reference(input)));
+}
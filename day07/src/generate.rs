@@ -0,0 +1,30 @@
+//! Random calibration equations. `size` is the number of equations.
+//!
+//! When solvable, every result comes from combining the operands with some of the operators
+//! (concatenation included). Otherwise the results are just random.
+
+use aoc_common::generate::{Params, Rng};
+
+pub fn input(rng: &mut Rng, params: &Params) -> String {
+    let mut text = String::new();
+
+    for _ in 0..params.size {
+        let operands = (0..rng.between(2, 8)).map(|_| rng.between(1, 99)).collect::<Vec<i64>>();
+
+        // Two digits operands at most, so not even eight concatenations overflow
+        let result = if params.solvable || rng.chance(0.5) {
+            operands[1..].iter().fold(operands[0], |acc, &n| match rng.below(3) {
+                0 => acc + n,
+                1 => acc * n,
+                _ => format!("{acc}{n}").parse().unwrap(),
+            })
+        } else {
+            rng.between(1, 1_000_000_000)
+        };
+
+        let operands = operands.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+        text.push_str(&format!("{result}: {}\n", operands.join(" ")));
+    }
+
+    text
+}
@@ -0,0 +1,108 @@
+pub mod generate;
+
+use std::io::{BufReader, Read};
+use aoc_common::{
+    error::{column_of, numbered_lines, parse_at, ParseError},
+    puzzle, Answer, ParseResult, Puzzle,
+};
+
+#[derive(Debug)]
+pub struct Equation {
+    result: i64,
+    operands: Vec<i64>,
+}
+
+/// The digits of `b` written after those of `a`, unless it doesn't fit
+fn concat(a: i64, b: i64) -> Option<i64> {
+    let shift = 10i64.checked_pow(b.checked_ilog10().unwrap_or(0) + 1)?;
+
+    a.checked_mul(shift)?.checked_add(b)
+}
+
+impl Equation {
+    // Operands are never negative, so a branch going past the result, or past what fits in
+    // an i64, can be dropped
+    fn is_valid_recursive(&self, so_far: i64, depth: usize, with_concat: bool) -> bool {
+        if so_far > self.result {
+            false
+        }
+        else if depth == self.operands.len() {
+            so_far == self.result
+        } else {
+            let next_operand = self.operands[depth];
+            let next = [
+                if with_concat { concat(so_far, next_operand) } else { None },
+                so_far.checked_add(next_operand),
+                so_far.checked_mul(next_operand),
+            ];
+
+            next.iter().any(|n| n.is_some_and(|n| self.is_valid_recursive(n, depth + 1, with_concat)))
+        }
+    }
+
+    fn is_valid(&self, with_concat: bool) -> bool {
+        self.is_valid_recursive(0, 0, with_concat)
+    }
+}
+
+fn parse_equation(n: usize, line: &str) -> ParseResult<Equation> {
+    let (result, operands) = line.split_once(':')
+        .ok_or_else(|| ParseError::new(n, line.chars().count() + 1, "':' after the result").found(""))?;
+
+    let operands = operands.split_whitespace()
+        .map(|s| match parse_at::<i64>(n, line, s, "an operand")? {
+            o if o < 0 => Err(ParseError::new(n, column_of(line, s), "a non-negative operand").found(s)),
+            o => Ok(o),
+        })
+        .collect::<ParseResult<Vec<_>>>()?;
+
+    if operands.is_empty() {
+        return Err(ParseError::new(n, line.chars().count() + 1, "at least one operand").found(""));
+    }
+
+    Ok(Equation {
+        result: parse_at(n, line, result.trim(), "the result")?,
+        operands,
+    })
+}
+
+fn read_input<R>(stream: BufReader<R>) -> ParseResult<Vec<Equation>>
+    where R: Read,
+{
+    numbered_lines(stream)
+        .map(|l| l.and_then(|(n, l)| parse_equation(n, &l)))
+        .collect()
+}
+
+/// No sum if it doesn't fit in an i64
+fn sum_valid(eqs: &[Equation], with_concat: bool) -> Option<i64> {
+    eqs.iter()
+        .filter(|&eq| eq.is_valid(with_concat))
+        .try_fold(0i64, |sum, eq| sum.checked_add(eq.result))
+}
+
+pub struct Day07;
+
+impl Puzzle for Day07 {
+    type Input = Vec<Equation>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        read_input(BufReader::new(input.as_bytes()))
+    }
+
+    fn part1(eqs: &Self::Input) -> Answer {
+        sum_valid(eqs, false).into()
+    }
+
+    fn part2(eqs: &Self::Input) -> Answer {
+        sum_valid(eqs, true).into()
+    }
+}
+
+pub fn part1(input: &str) -> ParseResult<Answer> {
+    puzzle::solve_part1::<Day07>(input)
+}
+
+pub fn part2(input: &str) -> ParseResult<Answer> {
+    puzzle::solve_part2::<Day07>(input)
+}
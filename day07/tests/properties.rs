@@ -0,0 +1,40 @@
+use aoc_common::{Answer, Puzzle};
+use day07::Day07;
+use proptest::prelude::*;
+
+// Small numbers, which make valid equations, and numbers on the verge of overflowing
+fn number() -> impl Strategy<Value = i64> {
+    prop_oneof![0..20i64, (i64::MAX - 20)..=i64::MAX, 0..=i64::MAX]
+}
+
+fn to_input(equations: &[(i64, Vec<i64>)]) -> String {
+    equations.iter()
+        .map(|(result, operands)| {
+            let operands = operands.iter().map(|o| o.to_string()).collect::<Vec<_>>();
+            format!("{result}: {}\n", operands.join(" "))
+        })
+        .collect()
+}
+
+proptest! {
+    #[test]
+    fn solves_huge_operands_without_panicking(equations in prop::collection::vec((number(), prop::collection::vec(number(), 1..6)), 1..6)) {
+        let input = Day07::parse(&to_input(&equations)).unwrap();
+
+        match (Day07::part1(&input), Day07::part2(&input)) {
+            // Concatenation only makes more equations valid
+            (Answer::Number(without), Answer::Number(with)) => prop_assert!(without <= with),
+            (Answer::Number(_) | Answer::Unsolved, Answer::Number(_) | Answer::Unsolved) => {},
+            answers => prop_assert!(false, "Unexpected answers {:?}", answers),
+        }
+    }
+
+    #[test]
+    fn rejects_negative_operands(result in number(), operands in prop::collection::vec(number(), 0..4), negative in i64::MIN..0) {
+        let mut operands = operands;
+        operands.push(negative);
+
+        prop_assert!(day07::part1(&to_input(&[(result, operands.clone())])).is_err());
+        prop_assert!(day07::part2(&to_input(&[(result, operands)])).is_err());
+    }
+}
@@ -0,0 +1,27 @@
+//! Random antenna maps. `size` is the side of the map.
+
+use aoc_common::{
+    generate::{Params, Rng},
+    Grid, Point,
+};
+
+const FREQUENCIES: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+pub fn input(rng: &mut Rng, params: &Params) -> String {
+    let side = params.size.max(1);
+    let mut map = Grid::new(side, side, '.');
+    let mut free = map.points().collect::<Vec<Point>>();
+    rng.shuffle(&mut free);
+
+    let frequencies = FREQUENCIES.chars().take((side / 2).clamp(1, FREQUENCIES.len())).collect::<Vec<_>>();
+
+    for &freq in frequencies.iter() {
+        for _ in 0..4 {
+            if let Some(p) = free.pop() {
+                map[p] = freq;
+            }
+        }
+    }
+
+    map.rows().map(|r| r.iter().collect::<String>() + "\n").collect()
+}
@@ -0,0 +1,196 @@
+pub mod generate;
+
+use std::{collections::{HashMap, HashSet}, io::{BufReader, Read}};
+use aoc_common::{
+    error::numbered_lines,
+    puzzle,
+    render::{Animation, Canvas, Cell, Rgb},
+    Answer, ParseError, ParseResult, Point, Puzzle,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Coords {
+    x: i64,
+    y: i64,
+}
+
+impl Coords {
+    fn from_input(row: usize, col: usize) -> Self {
+        Coords { x: col as i64, y: row as i64 }
+    }
+
+    fn distance(&self, other: &Coords) -> (i64, i64) {
+        (other.y - self.y, other.x - self.x)
+    }
+
+    fn add(&self, dy: i64, dx: i64) -> Coords {
+        Coords {
+            x: self.x + dx,
+            y: self.y + dy,
+        }
+    }
+
+    fn to_point(&self) -> Point {
+        Point { x: self.x as i32, y: self.y as i32 }
+    }
+
+    fn antinodes(&self, other: &Coords) -> Vec<Coords> {
+        let (dy, dx) = self.distance(other);
+
+        vec![
+            Coords { x: self.x - dx, y: self.y - dy },
+            Coords { x: other.x + dx, y: other.y + dy },
+        ]
+    }
+}
+
+#[derive(Debug)]
+pub struct Map {
+    width: i64,
+    height: i64,
+//    antennas: HashSet<Coords>,
+    antennas_by_frequency: HashMap<char, Vec<Coords>>
+}
+
+impl Map {
+    fn contains(&self, coords: &Coords) -> bool {
+        coords.x >= 0 && coords.x < self.width && coords.y >= 0 && coords.y < self.height
+    }
+
+    fn canvas(&self, antinodes: &HashSet<Coords>) -> Canvas {
+        let mut canvas = Canvas::new(self.width as usize, self.height as usize);
+        let antinodes = antinodes.iter().map(|an| an.to_point()).collect::<Vec<_>>();
+
+        canvas.mark(&antinodes, Cell::new('#', Rgb::WHITE));
+
+        for (&c, coords) in self.antennas_by_frequency.iter() {
+            for coord in coords {
+                canvas.draw(coord.to_point(), Cell::new(c, Rgb::CYAN));
+            }
+        }
+
+        // Antennas can be antinodes too
+        canvas.highlight(&antinodes, Rgb::MAGENTA);
+        canvas
+    }
+}
+
+fn find_antinodes_with_harmonics(map: &Map) -> HashSet<Coords> {
+    let mut antinodes = HashSet::new();
+
+    for nodes in map.antennas_by_frequency.values() {
+        for (index, a) in nodes.split_last().unwrap().1.iter().enumerate() {
+            for b in &nodes[index+1..] {
+                let (dy, dx) = a.distance(b);
+                antinodes.insert(a.clone());
+                antinodes.insert(b.clone());
+
+                let mut antinode = a.add(-dy, -dx);
+                while map.contains(&antinode) {
+                    antinodes.insert(antinode.clone());
+                    antinode = antinode.add(-dy, -dx);
+                }
+
+                let mut antinode = b.add(dy, dx);
+                while map.contains(&antinode) {
+                    antinodes.insert(antinode.clone());
+                    antinode = antinode.add(dy, dx);
+                }
+            }
+        }
+    }
+
+    antinodes
+}
+
+fn find_antinodes(map: &Map) -> HashSet<Coords> {
+    let mut antinodes = HashSet::new();
+
+    for nodes in map.antennas_by_frequency.values() {
+        for (index, a) in nodes.split_last().unwrap().1.iter().enumerate() {
+            for b in &nodes[index+1..] {
+                for an in a.antinodes(b).into_iter().filter(|c| map.contains(c)) {
+                    antinodes.insert(an);
+                }
+            }
+        }
+    }
+
+    antinodes
+}
+
+
+fn read_map<R>(stream: BufReader<R>) -> ParseResult<Map>
+    where R: Read,
+{
+    let mut _antennas: HashSet<Coords> = HashSet::new();
+    let mut antennas_by_frequency: HashMap<char, Vec<Coords>> = HashMap::new();
+
+    let lines = numbered_lines(stream).collect::<ParseResult<Vec<_>>>()?;
+    let height = lines.len() as i64;
+    let width = lines.first()
+        .ok_or_else(|| ParseError::new(1, 1, "a map").found(""))?
+        .1.chars().count();
+
+    for (n, line) in lines.iter() {
+        if line.chars().count() != width {
+            return Err(ParseError::new(*n, 1, format!("a row of {width} cells")).found(line.as_str()));
+        }
+        if let Some((col, c)) = line.chars().enumerate().find(|(_, c)| *c != '.' && !c.is_ascii_alphanumeric()) {
+            return Err(ParseError::new(*n, col + 1, "'.' or an antenna (a letter or digit)").found(c));
+        }
+    }
+
+    let width = width as i64;
+
+    for (row, (_, line)) in lines.into_iter().enumerate() {
+        for (col, c) in line.chars().enumerate().filter(|(_, c)| *c != '.') {
+            antennas_by_frequency.entry(c)
+                .and_modify(|v| v.push(Coords::from_input(row, col)))
+                .or_insert(vec![Coords::from_input(row, col)]);
+            _antennas.insert(Coords::from_input(row, col));
+        }
+    }
+
+    Ok(Map {
+        width,
+        height,
+//        antennas,
+        antennas_by_frequency,
+    })
+}
+
+pub struct Day08;
+
+impl Puzzle for Day08 {
+    type Input = Map;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        read_map(BufReader::new(input.as_bytes()))
+    }
+
+    fn part1(map: &Self::Input) -> Answer {
+        find_antinodes(map).len().into()
+    }
+
+    fn part2(map: &Self::Input) -> Answer {
+        find_antinodes_with_harmonics(map).len().into()
+    }
+
+    fn render(map: &Self::Input) -> Option<Animation> {
+        let mut animation = Animation::new();
+        animation.push(map.canvas(&HashSet::new()));
+        animation.push(map.canvas(&find_antinodes(map)));
+        animation.push(map.canvas(&find_antinodes_with_harmonics(map)));
+
+        Some(animation)
+    }
+}
+
+pub fn part1(input: &str) -> ParseResult<Answer> {
+    puzzle::solve_part1::<Day08>(input)
+}
+
+pub fn part2(input: &str) -> ParseResult<Answer> {
+    puzzle::solve_part2::<Day08>(input)
+}
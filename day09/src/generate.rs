@@ -0,0 +1,17 @@
+//! Random disk maps. `size` is the number of files.
+
+use aoc_common::generate::{Params, Rng};
+
+pub fn input(rng: &mut Rng, params: &Params) -> String {
+    let mut text = String::new();
+
+    for file in 0..params.size {
+        text.push_str(&rng.between(1, 9).to_string());
+        if file + 1 < params.size {
+            text.push_str(&rng.between(0, 9).to_string());
+        }
+    }
+
+    text.push('\n');
+    text
+}
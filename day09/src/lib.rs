@@ -0,0 +1,318 @@
+pub mod generate;
+
+use std::io::{BufRead, BufReader, Read};
+use aoc_common::{puzzle, Answer, ParseError, ParseResult, Puzzle};
+use std::fmt::Display;
+
+pub enum Fragmentation {
+    Allow,
+    DontAllow,
+}
+
+#[derive(Clone, Debug)]
+struct File {
+    id: usize, blocks: usize
+}
+
+impl File {
+    fn split(&mut self, blocks: usize) -> File {
+        self.blocks -= blocks;
+
+        File {
+            id: self.id,
+            blocks,
+        }
+    }
+
+    fn checksum(&self, first_block: usize) -> usize {
+        (first_block..(first_block+self.blocks)).map(|index| index*self.id).sum::<usize>()
+    }
+}
+
+impl Display for File {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let id = format!("{}", self.id);
+        let output = id.repeat(self.blocks);
+        write!(f, "{output}")
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Span {
+    freespace: usize,
+    content: Vec<File>,
+}
+
+impl Span {
+    fn last(&mut self) -> Option<&File> {
+        self.content.last()
+    }
+
+    fn split_last(&mut self, blocks: usize) -> File {
+        self.freespace += blocks;
+        self.content.last_mut().unwrap().split(blocks)
+    }
+
+    fn append(&mut self, file: File) {
+        assert!(self.freespace >= file.blocks);
+        self.freespace -= file.blocks;
+        self.content.push(file);
+    }
+
+    fn pop(&mut self) -> Option<File> {
+        self.content.pop().inspect(|f| self.freespace += f.blocks)
+    }
+
+    fn will_fit(&self, blocks: usize) -> bool {
+        self.freespace >= blocks
+    }
+
+    fn is_empty(&self) -> bool {
+        self.content.is_empty()
+    }
+
+    fn is_full(&self) -> bool {
+        self.freespace == 0
+    }
+
+    fn size(&self) -> usize {
+        self.freespace + self.content.iter().map(|f| f.blocks).sum::<usize>()
+    }
+
+    fn checksum(&self, first_block: usize) -> usize {
+        let mut curr_block = first_block;
+        let mut ret = 0usize;
+
+        for file in self.content.iter() {
+            ret += file.checksum(curr_block);
+            curr_block += file.blocks
+        }
+
+        ret
+    }
+}
+
+impl Display for Span {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let files = self.content
+            .iter()
+            .map(|f| format!("{f}"))
+            .collect::<String>();
+        let empty = if self.freespace == 0 { String::new() } else {
+            ".".repeat(self.freespace)
+        };
+
+        write!(f, "{files}{empty}|")
+    }
+}
+
+#[derive(Debug)]
+pub struct FileSystem {
+    structure: Vec<Span>,
+    data_pointer: usize,
+}
+
+impl FileSystem {
+    fn new(structure: Vec<Span>) -> Self {
+        // Not always the last span or the one before: empty files leave no span
+        let data_pointer = structure.iter().rposition(|s| !s.is_empty()).unwrap_or(0);
+
+        FileSystem {
+            structure,
+            data_pointer,
+        }
+    }
+
+    pub fn compact(&self, frag: Fragmentation) -> FileSystem {
+        let mut structure = self.structure.clone();
+
+        // Usually the second span, unless some files or free spaces are empty
+        let mut freespace_pointer = structure.iter().position(|s| !s.is_full()).unwrap_or(structure.len());
+        let mut data_pointer = self.data_pointer;
+        let mut last_data_block = None;
+
+        while freespace_pointer < data_pointer {
+            let (before_last_span, from_last_span) = structure.split_at_mut(data_pointer);
+            let data_span = &mut from_last_span[0];
+            let blocks_to_move = data_span.last().unwrap().blocks;
+
+            match frag {
+                Fragmentation::Allow => {
+                    let free_span = &mut before_last_span[freespace_pointer];
+
+                    if !free_span.will_fit(blocks_to_move) {
+                        free_span.append(data_span.split_last(free_span.freespace));
+                    } else {
+                        free_span.append(data_span.pop().unwrap());
+                    }
+                }
+                Fragmentation::DontAllow => {
+                    for free_span in &mut before_last_span[freespace_pointer..] {
+                        if free_span.will_fit(blocks_to_move) {
+                            free_span.append(data_span.pop().unwrap());
+                            break;
+                        } else if last_data_block.is_none() {
+                            last_data_block = Some(data_pointer);
+                        }
+                    }
+                    data_pointer -= 1;
+                }
+            }
+
+            while freespace_pointer < data_pointer && structure[freespace_pointer].is_full() {
+                freespace_pointer += 1;
+            }
+
+            while freespace_pointer < data_pointer && structure[data_pointer].is_empty() {
+                data_pointer -=1;
+            }
+        }
+
+        FileSystem {
+            structure,
+            data_pointer: last_data_block.unwrap_or(data_pointer),
+        }
+    }
+
+    pub fn checksum(&self) -> usize {
+        let mut curr_block = 0usize;
+        let mut ret = 0usize;
+
+        for (i, span) in self.structure.iter().enumerate() {
+            if i > self.data_pointer {
+                break;
+            }
+
+            ret += span.checksum(curr_block);
+            curr_block += span.size();
+        }
+
+        ret
+    }
+}
+
+impl FileSystem {
+    /// The id of the file stored in every block of the disk, or `None` for free blocks
+    pub fn blocks(&self) -> Vec<Option<usize>> {
+        let mut blocks = vec![];
+
+        for span in self.structure.iter() {
+            for file in span.content.iter() {
+                blocks.extend(std::iter::repeat_n(Some(file.id), file.blocks));
+            }
+            blocks.extend(std::iter::repeat_n(None, span.freespace));
+        }
+
+        blocks
+    }
+}
+
+impl Display for FileSystem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let spans = self.structure.iter().map(|s| format!("{s}")).collect::<String>();
+
+        write!(f, "{spans}")
+    }
+}
+
+struct Reader {
+    reading_file: bool,
+    next_id: usize,
+}
+
+impl Reader {
+    fn new() -> Self {
+        Reader {
+            reading_file: true,
+            next_id: 0,
+        }
+    }
+
+    fn next(&mut self, blocks: usize) -> Option<Span> {
+        let is_file = self.reading_file;
+        self.reading_file = !is_file;
+
+        // Even empty files take an id
+        let id = self.next_id;
+        if is_file {
+            self.next_id += 1;
+        }
+
+        if blocks == 0 {
+            None
+        } else {
+            let span = if is_file {
+                let content = vec![File { id, blocks }];
+
+                Span {
+                    freespace: 0,
+                    content
+                }
+            } else {
+                Span {
+                    freespace: blocks,
+                    content: vec![]
+                }
+            };
+
+            Some(span)
+        }
+    }
+}
+
+fn read_input<R>(mut stream: BufReader<R>) -> ParseResult<FileSystem>
+    where R: Read
+{
+    let mut buffer = String::new();
+    let mut reader = Reader::new();
+
+    stream.read_line(&mut buffer)
+        .map_err(|e| ParseError::new(1, 1, "a line of text").found(e.to_string()))?;
+
+    let spans = buffer
+        .trim_end()
+        .chars()
+        .enumerate()
+        .map(|(col, c)| c.to_digit(10)
+            .ok_or_else(|| ParseError::new(1, col + 1, "a digit").found(c)))
+        .collect::<ParseResult<Vec<_>>>()?
+        .into_iter()
+        .filter_map(|blocks| reader.next(blocks as usize));
+
+    // Without an empty file between them, free spaces are a single span
+    let mut structure: Vec<Span> = vec![];
+    for span in spans {
+        match structure.last_mut() {
+            Some(last) if last.is_empty() && span.is_empty() => last.freespace += span.freespace,
+            _ => structure.push(span),
+        }
+    }
+
+    Ok(FileSystem::new(structure))
+}
+
+pub struct Day09;
+
+impl Puzzle for Day09 {
+    type Input = FileSystem;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        read_input(BufReader::new(input.as_bytes()))
+    }
+
+    fn part1(filesys: &Self::Input) -> Answer {
+        filesys.compact(Fragmentation::Allow).checksum().into()
+    }
+
+    fn part2(filesys: &Self::Input) -> Answer {
+        filesys.compact(Fragmentation::DontAllow).checksum().into()
+    }
+}
+
+pub fn part1(input: &str) -> ParseResult<Answer> {
+    puzzle::solve_part1::<Day09>(input)
+}
+
+pub fn part2(input: &str) -> ParseResult<Answer> {
+    puzzle::solve_part2::<Day09>(input)
+}
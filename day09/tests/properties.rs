@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+
+use aoc_common::Puzzle;
+use day09::{Day09, FileSystem, Fragmentation};
+use proptest::prelude::*;
+
+fn file_blocks(fs: &FileSystem) -> HashMap<usize, usize> {
+    let mut counts = HashMap::new();
+    for id in fs.blocks().into_iter().flatten() {
+        *counts.entry(id).or_default() += 1;
+    }
+    counts
+}
+
+fn checksum_of(blocks: &[Option<usize>]) -> usize {
+    blocks.iter()
+        .enumerate()
+        .filter_map(|(pos, id)| id.map(|id| pos * id))
+        .sum()
+}
+
+// Straightforward block-by-block version of the whole-file compaction
+fn reference_whole_files(map: &str) -> Vec<Option<usize>> {
+    let mut blocks = vec![];
+    for (i, c) in map.chars().enumerate() {
+        let n = c.to_digit(10).unwrap() as usize;
+        let content = if i % 2 == 0 { Some(i / 2) } else { None };
+        blocks.extend(std::iter::repeat_n(content, n));
+    }
+
+    let files = map.len().div_ceil(2);
+    for id in (0..files).rev() {
+        let Some(start) = blocks.iter().position(|b| *b == Some(id)) else {
+            continue;
+        };
+        let len = blocks.iter().filter(|b| **b == Some(id)).count();
+        let mut run = 0;
+
+        for pos in 0..start {
+            run = if blocks[pos].is_none() { run + 1 } else { 0 };
+            if run == len {
+                let target = pos + 1 - len;
+                for k in 0..len {
+                    blocks[target + k] = Some(id);
+                    blocks[start + k] = None;
+                }
+                break;
+            }
+        }
+    }
+
+    blocks
+}
+
+// Files of 1-9 blocks, separated by 0-9 free blocks
+fn disk_map() -> impl Strategy<Value = String> {
+    disk_map_of(1..=9)
+}
+
+fn disk_map_of(file_sizes: std::ops::RangeInclusive<u8>) -> impl Strategy<Value = String> {
+    prop::collection::vec((file_sizes, 0..=9u8), 1..60)
+        .prop_map(|entries| entries.into_iter()
+            .flat_map(|(file, free)| [char::from(b'0' + file), char::from(b'0' + free)])
+            .collect())
+}
+
+proptest! {
+    #[test]
+    fn parsing_keeps_every_file(map in disk_map()) {
+        let fs = Day09::parse(&map).unwrap();
+        let expected = map.chars().step_by(2).map(|c| c.to_digit(10).unwrap() as usize).collect::<Vec<_>>();
+
+        prop_assert_eq!(fs.blocks().len(), map.chars().map(|c| c.to_digit(10).unwrap() as usize).sum::<usize>());
+        for (id, blocks) in expected.into_iter().enumerate() {
+            prop_assert_eq!(file_blocks(&fs).get(&id).copied(), Some(blocks));
+        }
+    }
+
+    #[test]
+    fn fragmenting_compaction_preserves_files(map in disk_map()) {
+        let fs = Day09::parse(&map).unwrap();
+        let compacted = fs.compact(Fragmentation::Allow);
+        let blocks = compacted.blocks();
+
+        prop_assert_eq!(file_blocks(&fs), file_blocks(&compacted));
+        // No gaps left between files
+        let used = blocks.iter().filter(|b| b.is_some()).count();
+        prop_assert!(blocks[..used].iter().all(|b| b.is_some()));
+        prop_assert_eq!(compacted.checksum(), checksum_of(&blocks));
+    }
+
+    #[test]
+    fn whole_file_compaction_preserves_files(map in disk_map()) {
+        let fs = Day09::parse(&map).unwrap();
+        let compacted = fs.compact(Fragmentation::DontAllow);
+        let blocks = compacted.blocks();
+
+        prop_assert_eq!(file_blocks(&fs), file_blocks(&compacted));
+        // Files are moved whole, never split
+        for id in file_blocks(&fs).keys() {
+            let first = blocks.iter().position(|b| *b == Some(*id)).unwrap();
+            let last = blocks.iter().rposition(|b| *b == Some(*id)).unwrap();
+            prop_assert!(blocks[first..=last].iter().all(|b| *b == Some(*id)));
+        }
+        prop_assert_eq!(compacted.checksum(), checksum_of(&blocks));
+    }
+
+    #[test]
+    fn whole_file_compaction_matches_reference(map in disk_map()) {
+        let compacted = Day09::parse(&map).unwrap().compact(Fragmentation::DontAllow);
+        let expected = reference_whole_files(&map);
+
+        prop_assert_eq!(compacted.checksum(), checksum_of(&expected));
+    }
+
+    #[test]
+    fn compacts_around_empty_files(map in disk_map_of(0..=9)) {
+        let fs = Day09::parse(&map).unwrap();
+        let fragmented = fs.compact(Fragmentation::Allow);
+        let whole = fs.compact(Fragmentation::DontAllow);
+
+        prop_assert_eq!(file_blocks(&fs), file_blocks(&fragmented));
+        prop_assert_eq!(fragmented.checksum(), checksum_of(&fragmented.blocks()));
+        prop_assert_eq!(whole.checksum(), checksum_of(&reference_whole_files(&map)));
+    }
+}
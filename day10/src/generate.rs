@@ -0,0 +1,27 @@
+//! Random topographic maps, with some hiking trails carved in them. `size` is the side of the map.
+
+use aoc_common::{
+    generate::{Params, Rng},
+    Grid, Point,
+};
+
+pub fn input(rng: &mut Rng, params: &Params) -> String {
+    let side = params.size.max(1);
+    let mut map = Grid::new(side, side, 0u8).map(|_| rng.below(10) as u8);
+
+    for _ in 0..side * side / 20 {
+        let mut p = Point::from_row_col(rng.index(side), rng.index(side));
+
+        for height in 0..=9 {
+            map[p] = height;
+
+            let neighbors = map.neighbors4(p).collect::<Vec<_>>();
+            if neighbors.is_empty() {
+                break;
+            }
+            p = *rng.pick(&neighbors);
+        }
+    }
+
+    map.rows().map(|r| r.iter().map(|h| h.to_string()).collect::<String>() + "\n").collect()
+}
@@ -0,0 +1,122 @@
+pub mod generate;
+
+use std::{collections::{HashMap, HashSet}, io::{BufReader, Read}};
+use aoc_common::{puzzle, Answer, Grid, ParseResult, Point, Puzzle};
+
+const PEAK: u8 = 9;
+
+pub struct Map {
+    heights: Grid<u8>,
+    bottoms: Vec<Point>,
+}
+
+impl Map {
+    fn new(heights: Grid<u8>) -> Self {
+        let bottoms = heights.positions(|&val| val == 0).collect::<Vec<_>>();
+
+        Map {
+            heights,
+            bottoms,
+        }
+    }
+
+    fn is_valid_step(&self, from_coord: &Point, to_coord: &Point) -> bool {
+        match (self.heights.get(*from_coord), self.heights.get(*to_coord)) {
+            (Some(&from), Some(&to)) => to == from + 1,
+            _ => false,
+        }
+    }
+
+    fn next_steps(&self, from: Point) -> impl Iterator<Item = Point> + '_ {
+        self.heights.neighbors4(from)
+            .filter(move |next_coords| self.is_valid_step(&from, next_coords))
+    }
+
+    // The peaks that can be reached from a point. Memoized, as many trails share their tails
+    fn reachable_peaks(&self, from: Point, memo: &mut HashMap<Point, HashSet<Point>>) -> HashSet<Point> {
+        if let Some(peaks) = memo.get(&from) {
+            return peaks.clone();
+        }
+
+        let peaks = if self.heights[from] == PEAK {
+            HashSet::from([from])
+        } else {
+            self.next_steps(from)
+                .flat_map(|next| self.reachable_peaks(next, memo))
+                .collect()
+        };
+
+        memo.insert(from, peaks.clone());
+        peaks
+    }
+
+    // The number of different trails that go from a point to any peak
+    fn distinct_trails(&self, from: Point, memo: &mut HashMap<Point, usize>) -> usize {
+        if let Some(&trails) = memo.get(&from) {
+            return trails;
+        }
+
+        let trails = if self.heights[from] == PEAK {
+            1
+        } else {
+            self.next_steps(from)
+                .map(|next| self.distinct_trails(next, memo))
+                .sum()
+        };
+
+        memo.insert(from, trails);
+        trails
+    }
+
+    fn trailhead_scores(&self) -> usize {
+        let mut memo = HashMap::new();
+
+        self.bottoms.iter()
+            .map(|&start| self.reachable_peaks(start, &mut memo).len())
+            .sum()
+    }
+
+    fn trailhead_ratings(&self) -> usize {
+        let mut memo = HashMap::new();
+
+        self.bottoms.iter()
+            .map(|&start| self.distinct_trails(start, &mut memo))
+            .sum()
+    }
+}
+
+fn read_map<R>(stream: BufReader<R>) -> ParseResult<Map>
+    where R: Read
+{
+    let heights = Grid::parse(stream, |c| {
+        c.to_digit(10).map(|h| h as u8).ok_or_else(|| "a height (0-9)".to_string())
+    })?;
+
+    Ok(Map::new(heights))
+}
+
+pub struct Day10;
+
+impl Puzzle for Day10 {
+    type Input = Map;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        read_map(BufReader::new(input.as_bytes()))
+    }
+
+    fn part1(map: &Self::Input) -> Answer {
+        map.trailhead_scores().into()
+    }
+
+    fn part2(map: &Self::Input) -> Answer {
+        map.trailhead_ratings().into()
+    }
+}
+
+pub fn part1(input: &str) -> ParseResult<Answer> {
+    puzzle::solve_part1::<Day10>(input)
+}
+
+pub fn part2(input: &str) -> ParseResult<Answer> {
+    puzzle::solve_part2::<Day10>(input)
+}
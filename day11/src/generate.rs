@@ -0,0 +1,9 @@
+//! Random stone engravings. `size` is the number of stones.
+
+use aoc_common::generate::{Params, Rng};
+
+pub fn input(rng: &mut Rng, params: &Params) -> String {
+    let stones = (0..params.size.max(1)).map(|_| rng.between(0, 999_999).to_string()).collect::<Vec<_>>();
+
+    stones.join(" ") + "\n"
+}
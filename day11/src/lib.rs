@@ -0,0 +1,112 @@
+pub mod generate;
+
+use std::{collections::HashMap, fmt::Display, io::{BufRead, BufReader, Read}, ops::Deref};
+use aoc_common::{error::parse_at, puzzle, Answer, ParseError, ParseResult, Puzzle};
+
+#[derive(Clone, Debug)]
+pub struct Stone(usize);
+
+impl Display for Stone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Stone {
+    fn is_even_digits(&self) -> bool {
+        self.to_string().len().is_multiple_of(2)
+    }
+
+    /// The stones this one turns into, unless the number engraved gets too big
+    fn blink(&self) -> Option<Vec<Stone>> {
+        match self.0 {
+            0 => Some(vec![Stone(1)]),
+            _ if self.is_even_digits() => {
+                let s = self.to_string();
+                let (l, r) = s.split_at(s.len() / 2);
+                Some(vec![Stone(l.parse().ok()?), Stone(r.parse().ok()?)])
+            }
+            _ => Some(vec![Stone(self.0.checked_mul(2024)?)]),
+        }
+    }
+}
+
+impl Deref for Stone {
+    type Target = usize;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+struct Blinker {
+    memo: HashMap<(usize, usize), Option<usize>>
+}
+
+impl Blinker {
+    fn new() -> Self {
+        Blinker { memo: HashMap::new() }
+    }
+
+    // None when the numbers or the count of stones get too big
+    fn blink_single(&mut self, stone: &Stone, times: usize) -> Option<usize> {
+        match times {
+            0 => Some(1),
+            1 => stone.blink().map(|stones| stones.len()),
+            _ => {
+                let stone_val = *stone.deref();
+                if let Some(value) = self.memo.get(&(stone_val, times)) {
+                    *value
+                } else {
+                    let value = stone.blink().and_then(|stones| stones.iter()
+                        .try_fold(0usize, |sum, s| sum.checked_add(self.blink_single(s, times - 1)?)));
+                    self.memo.insert((stone_val, times), value);
+
+                    value
+                }
+            }
+        }
+    }
+
+    fn after_blinking_times(&mut self, stones: &[Stone], times: usize) -> Option<usize> {
+        stones.iter().try_fold(0usize, |sum, s| sum.checked_add(self.blink_single(s, times)?))
+    }
+}
+
+fn read_input<R>(mut stream: BufReader<R>) -> ParseResult<Vec<Stone>>
+    where R: Read
+{
+    let mut line = String::new();
+    stream.read_line(&mut line)
+        .map_err(|e| ParseError::new(1, 1, "a line of text").found(e.to_string()))?;
+
+    line.split_whitespace()
+        .map(|s| parse_at(1, &line, s, "a stone number").map(Stone))
+        .collect()
+}
+
+pub struct Day11;
+
+impl Puzzle for Day11 {
+    type Input = Vec<Stone>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        read_input(BufReader::new(input.as_bytes()))
+    }
+
+    fn part1(stones: &Self::Input) -> Answer {
+        Blinker::new().after_blinking_times(stones, 25).into()
+    }
+
+    fn part2(stones: &Self::Input) -> Answer {
+        Blinker::new().after_blinking_times(stones, 75).into()
+    }
+}
+
+pub fn part1(input: &str) -> ParseResult<Answer> {
+    puzzle::solve_part1::<Day11>(input)
+}
+
+pub fn part2(input: &str) -> ParseResult<Answer> {
+    puzzle::solve_part2::<Day11>(input)
+}
@@ -0,0 +1,23 @@
+//! Random garden maps, with plants growing in patches. `size` is the side of the map.
+
+use aoc_common::{
+    generate::{Params, Rng},
+    Direction, Grid,
+};
+
+pub fn input(rng: &mut Rng, params: &Params) -> String {
+    let side = params.size.max(1);
+    let mut map = Grid::new(side, side, 'A');
+
+    for p in map.points().collect::<Vec<_>>() {
+        // Mostly the same plant as the one to the left or above, to make bigger regions
+        let neighbor = p.step(*rng.pick(&[Direction::Left, Direction::Up]));
+
+        map[p] = match map.get(neighbor) {
+            Some(&plant) if rng.chance(0.8) => plant,
+            _ => (b'A' + rng.below(26) as u8) as char,
+        };
+    }
+
+    map.rows().map(|r| r.iter().collect::<String>() + "\n").collect()
+}
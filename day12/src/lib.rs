@@ -0,0 +1,197 @@
+pub mod generate;
+
+use std::{collections::{HashMap, HashSet}, io::{BufReader, Read}};
+use aoc_common::{puzzle, Answer, Direction, Grid, ParseResult, Point, Puzzle};
+
+fn neighbor_set(point: &Point) -> HashSet<Point> {
+    HashSet::from_iter(point.neighbors4())
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Side {
+    start: Point,
+    end: Point,
+}
+
+impl Side {
+    fn adjacent(&self, side: &Direction, other: &Point) -> bool {
+        match side {
+            Direction::Up | Direction::Down => { self.end.y == other.y && (self.end.x - other.x).abs() == 1 },
+            Direction::Left | Direction::Right => { self.end.x == other.x && (self.end.y - other.y).abs() == 1 },
+        }
+    }
+
+    fn absorb(&mut self, other: Point) {
+        if self.end.x < other.x || self.end.y < other.y {
+            self.end = other;
+        } else {
+            self.start = other;
+        }
+    }
+}
+
+impl From<Point> for Side {
+    fn from(value: Point) -> Self {
+        Side {
+            start: value,
+            end: value,
+        }
+    }
+}
+
+impl PartialOrd for Side {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Side {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.start.cmp(&other.start)
+    }
+}
+
+#[derive(Debug)]
+struct Region {
+    #[allow(dead_code)]
+    plant: char,
+    plots: Vec<Point>,
+}
+
+impl Region {
+    fn area(&self) -> usize {
+        self.plots.len()
+    }
+
+    fn perimeter(&self) -> usize {
+        let plot_set = HashSet::from_iter(self.plots.iter().cloned());
+
+        self.plots
+            .iter()
+            .map(|p| neighbor_set(p).difference(&plot_set).count())
+            .sum::<usize>()
+    }
+
+    fn sides(&self) -> usize {
+        let plot_set: HashSet<Point> = HashSet::from_iter(self.plots.iter().cloned());
+
+        let mut plots_by_facing: HashMap<Direction, Vec<Point>> = HashMap::new();
+
+        for plot in plot_set.iter() {
+            Direction::ALL.iter()
+                .filter(|&&facing| !plot_set.contains(&plot.step(facing)))
+                .for_each(|&facing| {
+                    plots_by_facing
+                        .entry(facing)
+                        .and_modify(|v| v.push(*plot))
+                        .or_insert(vec![*plot]);
+                });
+        }
+
+        let mut num_sides = 0usize;
+
+        for (dir, mut plots) in plots_by_facing.into_iter() {
+            // Reverse reading order, so that we pop the plots top to bottom, left to right
+            plots.sort_by(|a, b| b.cmp(a));
+            let mut fused = vec![Side::from(plots.pop().unwrap())];
+
+            while let Some(plot) = plots.pop() {
+                if let Some(matching) = fused.iter_mut().find(|s| s.adjacent(&dir, &plot)) {
+                    matching.absorb(plot);
+                } else {
+                    fused.push(Side::from(plot))
+                }
+            }
+
+            num_sides += fused.len();
+        }
+
+
+        num_sides
+    }
+
+    fn fencing_cost(&self, discounted: bool) -> usize {
+        self.area() * if discounted { self.sides() } else { self.perimeter() }
+    }
+}
+
+pub struct Map {
+    plants: Grid<char>,
+}
+
+impl Map {
+    fn matches_plant(&self, plant: char, plot: &Point) -> bool {
+        self.plants.get(*plot).is_some_and(|&p| p == plant)
+    }
+
+    fn flood_find_region(&self, seed: &Point) -> (Region, HashSet<Point>) {
+        let plant = self.plants[*seed];
+        let mut plots = vec![];
+        let mut visited: HashSet<Point> = HashSet::new();
+        let mut candidates = vec![*seed];
+
+        while let Some(plot) = candidates.pop() {
+            if !visited.contains(&plot) {
+                visited.insert(plot);
+
+                if self.matches_plant(plant, &plot) {
+                    candidates.extend(self.plants.neighbors4(plot));
+                    plots.push(plot);
+                }
+            }
+        }
+
+        let plot_set = HashSet::from_iter(plots.iter().cloned());
+        (Region { plant, plots }, plot_set)
+    }
+
+    fn generate_regions(&self) -> Vec<Region> {
+        let mut found: HashSet<Point> = HashSet::new();
+        let mut regions = vec![];
+
+        for plot in self.plants.points() {
+            if !found.contains(&plot) {
+                let (region, visited) = self.flood_find_region(&plot);
+                regions.push(region);
+                found.extend(visited);
+            }
+        }
+
+        regions
+    }
+}
+
+fn read_input<R>(stream: BufReader<R>) -> ParseResult<Map>
+where 
+    R: Read
+{
+    Ok(Map {
+        plants: Grid::parse_chars(stream)?,
+    })
+}
+
+pub struct Day12;
+
+impl Puzzle for Day12 {
+    type Input = Map;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        read_input(BufReader::new(input.as_bytes()))
+    }
+
+    fn part1(map: &Self::Input) -> Answer {
+        map.generate_regions().iter().map(|r| r.fencing_cost(false)).sum::<usize>().into()
+    }
+
+    fn part2(map: &Self::Input) -> Answer {
+        map.generate_regions().iter().map(|r| r.fencing_cost(true)).sum::<usize>().into()
+    }
+}
+
+pub fn part1(input: &str) -> ParseResult<Answer> {
+    puzzle::solve_part1::<Day12>(input)
+}
+
+pub fn part2(input: &str) -> ParseResult<Answer> {
+    puzzle::solve_part2::<Day12>(input)
+}
@@ -0,0 +1,53 @@
+//! Random claw machines. `size` is the number of machines.
+//!
+//! When solvable, every prize can be won with at most 100 presses of each button, and the
+//! buttons are never parallel, so there's exactly one way of winning it.
+
+use aoc_common::generate::{Params, Rng};
+
+use crate::{Coords, Machine, COST_A_BUTTON, COST_B_BUTTON};
+
+fn button(rng: &mut Rng) -> Coords {
+    Coords { x: rng.between(10, 99), y: rng.between(10, 99) }
+}
+
+/// The input, along with the tokens needed to win all the prizes that can be won, if that's
+/// known (ie. when solvable)
+pub fn input_with_tokens(rng: &mut Rng, params: &Params) -> (String, Option<u64>) {
+    let mut machines = vec![];
+    let mut tokens = 0;
+
+    while machines.len() < params.size {
+        let button_a = button(rng);
+        let button_b = button(rng);
+        if button_a.x * button_b.y == button_a.y * button_b.x {
+            continue;
+        }
+
+        let prize_at = if params.solvable {
+            let (times_a, times_b) = (rng.between(0, 100), rng.between(0, 100));
+            tokens += times_a as u64 * COST_A_BUTTON + times_b as u64 * COST_B_BUTTON;
+
+            Coords {
+                x: times_a * button_a.x + times_b * button_b.x,
+                y: times_a * button_a.y + times_b * button_b.y,
+            }
+        } else {
+            Coords { x: rng.between(1000, 20000), y: rng.between(1000, 20000) }
+        };
+
+        machines.push(Machine { button_a, button_b, prize_at });
+    }
+
+    let text = machines.iter()
+        .map(|m| format!("Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+            m.button_a.x, m.button_a.y, m.button_b.x, m.button_b.y, m.prize_at.x, m.prize_at.y))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    (text, params.solvable.then_some(tokens))
+}
+
+pub fn input(rng: &mut Rng, params: &Params) -> String {
+    input_with_tokens(rng, params).0
+}
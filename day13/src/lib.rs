@@ -0,0 +1,240 @@
+pub mod generate;
+
+use std::io::{BufReader, Read};
+use aoc_common::{
+    error::{column_of, numbered_lines, parse_at},
+    puzzle, Answer, ParseError, ParseResult, Puzzle,
+};
+
+fn to_i64(n: usize, line: &str, raw: &str, sep: &str) -> ParseResult<i64> {
+    let (_, number) = raw.split_once(sep)
+        .ok_or_else(|| ParseError::new(n, column_of(line, raw), format!("'{sep}' followed by a number")).found(raw))?;
+
+    parse_at(n, line, number, "a number")
+}
+
+#[derive(Debug, Clone)]
+struct Coords {
+    x: i64,
+    y: i64,
+}
+
+impl Coords {
+    fn from_bits(n: usize, line: &str, bits: (&str, &str), sep: &str) -> ParseResult<Self> {
+        Ok(Coords {
+            x: to_i64(n, line, bits.0, sep)?,
+            y: to_i64(n, line, bits.1, sep)?,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct Solution {
+    times_a: u64,
+    times_b: u64,
+}
+
+impl Solution {
+    fn cost(&self, tokens_a: u64, tokens_b: u64) -> Option<u64> {
+        self.times_a.checked_mul(tokens_a)?.checked_add(self.times_b.checked_mul(tokens_b)?)
+    }
+}
+
+// The determinants of coordinates fitting in an i64 always fit in an i128
+#[derive(Debug)]
+struct EqSystem {
+    a: i128,
+    b: i128,
+    c: i128,
+    d: i128,
+    s1: i128,
+    s2: i128,
+}
+
+impl EqSystem {
+    fn new(c_a: &Coords, c_b: &Coords, res: &Coords) -> Self {
+        EqSystem {
+            a: c_a.x.into(),
+            b: c_b.x.into(),
+            c: c_a.y.into(),
+            d: c_b.y.into(),
+            s1: res.x.into(),
+            s2: res.y.into(),
+        }
+    }
+
+    fn solve(&self) -> Option<(u64, u64)> {
+        let det_a = (self.a * self.d) - (self.c * self.b);
+        let det1 = (self.s1 * self.d) - (self.s2 * self.b);
+        let det2 = (self.a * self.s2) - (self.c * self.s1);
+
+        if det_a == 0 {
+            None
+        } else {
+            let r1 = det1 % det_a;
+            let r2 = det2 % det_a;
+            let a_presses = det1 / det_a;
+            let b_presses = det2 / det_a;
+
+            if r1 != 0 || r2 != 0 {
+                None
+            } else {
+                Some((u64::try_from(a_presses).ok()?, u64::try_from(b_presses).ok()?))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Machine {
+    button_a: Coords,
+    button_b: Coords,
+    prize_at: Coords,
+}
+
+static BUMP: i64 = 10000000000000;
+
+impl Machine {
+    fn find_solution(&self, max_presses: Option<u64>) -> Option<Solution> {
+        let eqs = EqSystem::new(&self.button_a, &self.button_b, &self.prize_at);
+        eqs.solve().and_then(|(times_a, times_b)| {
+            if max_presses.is_some_and(|mp| times_a.max(times_b) > mp) {
+                None
+            } else {
+                Some(Solution { times_a, times_b })
+            }
+        })
+    }
+
+    fn bump(self) -> Option<Machine> {
+        Some(Machine {
+            prize_at: Coords { x: self.prize_at.x.checked_add(BUMP)?, y: self.prize_at.y.checked_add(BUMP)? },
+            .. self
+        })
+    }
+}
+
+#[derive(Default)]
+struct MachineBuilder {
+    button_a: Option<Coords>,
+    button_b: Option<Coords>,
+    prize_at: Option<Coords>,
+}
+
+impl MachineBuilder {
+    fn set_button_a(self, delta: Coords) -> MachineBuilder {
+        MachineBuilder {
+            button_a: Some(delta),
+            .. self
+        }
+    }
+
+    fn set_button_b(self, delta: Coords) -> MachineBuilder {
+        MachineBuilder {
+            button_b: Some(delta),
+            .. self
+        }
+    }
+
+    fn set_prize_at(self, pos: Coords) -> MachineBuilder {
+        MachineBuilder {
+            prize_at: Some(pos),
+            .. self
+        }
+    }
+
+    // `n` is the line of the prize, which completes the machine
+    fn build(self, n: usize) -> ParseResult<Machine> {
+        let missing = |what: &str| ParseError::new(n, 1, format!("a '{what}' line before the prize"));
+
+        Ok(Machine {
+            button_a: self.button_a.ok_or_else(|| missing("Button A"))?,
+            button_b: self.button_b.ok_or_else(|| missing("Button B"))?,
+            prize_at: self.prize_at.ok_or_else(|| missing("Prize"))?,
+        })
+    }
+}
+
+fn get_bits(n: usize, line: &str) -> ParseResult<(&str, &str)>
+{
+    line.split_once(": ")
+        .and_then(|(_, bits)| bits.split_once(", "))
+        .ok_or_else(|| ParseError::new(n, 1, "two comma-separated coordinates after ': '").found(line))
+}
+
+fn read_problem<R>(stream: BufReader<R>) -> ParseResult<Vec<Machine>>
+where
+    R: Read
+{
+    let mut machines = vec![];
+    let mut builder = MachineBuilder::default();
+
+    for line in numbered_lines(stream) {
+        let (n, line) = line?;
+        let line = line.trim_end();
+
+        if line.starts_with("Button A:") {
+            builder = builder.set_button_a(Coords::from_bits(n, line, get_bits(n, line)?, "+")?);
+        } else if line.starts_with("Button B:") {
+            builder = builder.set_button_b(Coords::from_bits(n, line, get_bits(n, line)?, "+")?);
+        } else if line.starts_with("Prize:") {
+            machines.push(builder
+                .set_prize_at(Coords::from_bits(n, line, get_bits(n, line)?, "=")?)
+                .build(n)?
+            );
+
+            builder = MachineBuilder::default();
+        } else if !line.is_empty() {
+            return Err(ParseError::new(n, 1, "'Button A:', 'Button B:' or 'Prize:'").found(line));
+        }
+    }
+
+    Ok(machines)
+}
+
+const COST_A_BUTTON: u64 = 3;
+const COST_B_BUTTON: u64 = 1;
+
+/// No total if a bumped prize or the number of tokens doesn't fit in an i64
+fn total_tokens(machines: &[Machine], bumped: bool) -> Option<i64> {
+    let mut total_tokens = 0u64;
+    for machine in machines {
+        let solution = if bumped {
+            machine.clone().bump()?.find_solution(None)
+        } else {
+            machine.find_solution(Some(100))
+        };
+
+        if let Some(solution) = solution {
+            total_tokens = total_tokens.checked_add(solution.cost(COST_A_BUTTON, COST_B_BUTTON)?)?;
+        }
+    }
+
+    i64::try_from(total_tokens).ok()
+}
+
+pub struct Day13;
+
+impl Puzzle for Day13 {
+    type Input = Vec<Machine>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        read_problem(BufReader::new(input.as_bytes()))
+    }
+
+    fn part1(machines: &Self::Input) -> Answer {
+        total_tokens(machines, false).into()
+    }
+
+    fn part2(machines: &Self::Input) -> Answer {
+        total_tokens(machines, true).into()
+    }
+}
+
+pub fn part1(input: &str) -> ParseResult<Answer> {
+    puzzle::solve_part1::<Day13>(input)
+}
+
+pub fn part2(input: &str) -> ParseResult<Answer> {
+    puzzle::solve_part2::<Day13>(input)
+}
@@ -0,0 +1,10 @@
+use aoc_common::generate::{Params, Rng};
+
+#[test]
+fn generated_machines_cost_the_known_tokens() {
+    for seed in 0..10 {
+        let (input, tokens) = day13::generate::input_with_tokens(&mut Rng::new(seed), &Params { size: 50, solvable: true });
+
+        assert_eq!(day13::part1(&input).unwrap().to_string(), tokens.unwrap().to_string());
+    }
+}
@@ -0,0 +1,49 @@
+//! Random robots in the puzzle's room. `size` is the number of robots.
+//!
+//! When solvable, some of the robots line up at some point in the first 10000 seconds, which is
+//! what the search for the Christmas tree looks for. There are at least 31 robots then.
+
+use aoc_common::generate::{Params, Rng};
+
+use crate::{Point, Robot, Room};
+
+const LINED_UP: usize = 31;
+
+fn velocity(rng: &mut Rng) -> (i32, i32) {
+    (rng.between(-99, 99) as i32, rng.between(-99, 99) as i32)
+}
+
+pub fn input(rng: &mut Rng, params: &Params) -> String {
+    let room = Room::PUZZLE;
+    let count = if params.solvable { params.size.max(LINED_UP) } else { params.size.max(1) };
+    let mut robots = vec![];
+
+    // Where the line up happens, and when
+    let (row, first_column) = (rng.between(0, room.tall as i64 - 1) as i32, rng.between(0, (room.wide - LINED_UP as i32) as i64) as i32);
+    let seconds = rng.between(100, 9999) as i32;
+
+    for i in 0..count {
+        let (dx, dy) = velocity(rng);
+
+        let robot = if params.solvable && i < LINED_UP {
+            // Placed in the line, then taken back in time
+            let lined_up = Robot { pos: Point { x: first_column + i as i32, y: row }, dx, dy };
+            Robot { pos: lined_up.move_by(-seconds, &room).pos, dx, dy }
+        } else {
+            let x = rng.between(0, room.wide as i64 - 1) as i32;
+            let y = rng.between(0, room.tall as i64 - 1) as i32;
+            Robot { pos: Point { x, y }, dx, dy }
+        };
+
+        robots.push(robot);
+    }
+
+    // The example room is picked when all the robots fit in it
+    if robots.iter().all(|r| r.pos.x < Room::EXAMPLE.wide && r.pos.y < Room::EXAMPLE.tall) {
+        robots[0].pos.x = room.wide - 1;
+    }
+
+    robots.iter()
+        .map(|r| format!("p={},{} v={},{}\n", r.pos.x, r.pos.y, r.dx, r.dy))
+        .collect()
+}
@@ -0,0 +1,177 @@
+pub mod generate;
+
+use std::{collections::HashMap, io::{BufReader, Read}};
+use aoc_common::{
+    error::{column_of, numbered_lines, parse_at},
+    puzzle, Answer, ParseError, ParseResult, Puzzle,
+};
+
+#[derive(Debug, Clone, Copy)]
+struct Room {
+    wide: i32,
+    tall: i32,
+}
+
+impl Room {
+    const PUZZLE: Room = Room { wide: 101, tall: 103 };
+    const EXAMPLE: Room = Room { wide: 11, tall: 7 };
+
+    // The example takes place in a much smaller room. If all robots fit in it, that's the one
+    fn for_robots(robots: &[Robot]) -> Room {
+        let example = Room::EXAMPLE;
+
+        if robots.iter().all(|r| r.pos.x < example.wide && r.pos.y < example.tall) {
+            example
+        } else {
+            Room::PUZZLE
+        }
+    }
+
+    fn quadrant(&self, pos: &Point) -> Option<u32> {
+        let (mid_wide, mid_tall) = (self.wide / 2, self.tall / 2);
+
+        match (pos.x, pos.y) {
+            (x, y) if x == mid_wide || y == mid_tall => None,
+            (x, y) => {
+                if x < mid_wide && y < mid_tall { Some(0) }
+                else if x < mid_wide && y > mid_tall { Some(1) }
+                else if x > mid_wide && y < mid_tall { Some(2) }
+                else { Some(3) }
+            }
+        }
+    }
+}
+
+fn coords_from_text(n: usize, line: &str, raw: &str) -> ParseResult<(i32, i32)> {
+    let (x, y) = raw.split_once('=')
+        .and_then(|(_, numbers)| numbers.split_once(','))
+        .ok_or_else(|| ParseError::new(n, column_of(line, raw), "a pair like 'p=x,y'").found(raw))?;
+
+    Ok((parse_at(n, line, x, "a number")?, parse_at(n, line, y, "a number")?))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Debug, Clone)]
+pub struct Robot {
+    pos: Point,
+    dx: i32,
+    dy: i32,
+}
+
+impl Robot {
+    // Only the velocity modulo the size of the room matters, which keeps the products small
+    fn move_by(&self, steps: i32, room: &Room) -> Self {
+        let along = |pos: i32, delta: i32, size: i32| {
+            let moved = i64::from(pos) + i64::from(delta.rem_euclid(size)) * i64::from(steps);
+            moved.rem_euclid(size.into()) as i32
+        };
+        let x = along(self.pos.x, self.dx, room.wide);
+        let y = along(self.pos.y, self.dy, room.tall);
+
+        Robot {
+            pos: Point { x, y },
+            dx: self.dx,
+            dy: self.dy,
+        }
+    }
+}
+
+impl Robot {
+    fn parse(n: usize, line: &str) -> ParseResult<Self> {
+        let (position, velocity) = line.split_once(' ')
+            .ok_or_else(|| ParseError::new(n, 1, "a position and a velocity, separated by a space").found(line))?;
+
+        let (x, y) = coords_from_text(n, line, position)?;
+        let (dx, dy) = coords_from_text(n, line, velocity)?;
+        let pos = Point { x, y };
+
+        Ok(Robot { pos, dx, dy })
+    }
+}
+
+fn might_be_tree(robots: &[Robot]) -> bool {
+    let mut at_line: HashMap<i32, Vec<i32>> = HashMap::new();
+
+    for r in robots.iter() {
+        at_line.entry(r.pos.y)
+            .and_modify(|v| v.push(r.pos.x))
+            .or_insert(vec![1]);
+    }
+
+    for value in at_line.into_values() {
+        if value.len() > 20 {
+            let mut value = value;
+            value.sort();
+            let (consec, _) = value[1..]
+                .iter()
+                .fold((0, value[0]),
+                    |(acc, prev_x), x| if prev_x.checked_add(1) == Some(*x) { (acc + 1, *x) } else { (acc, *x) } );
+            return consec > 15
+        }
+    }
+
+    false
+}
+
+fn read_problem<R>(stream: BufReader<R>) -> ParseResult<Vec<Robot>>
+where
+    R: Read,
+{
+    numbered_lines(stream)
+        .map(|line| line.and_then(|(n, line)| Robot::parse(n, line.trim_end())))
+        .collect()
+}
+
+fn safety_factor(robots: &[Robot]) -> usize {
+    let room = Room::for_robots(robots);
+    let mut in_quadrant = [0usize; 4];
+    robots.iter()
+        .filter_map(|r| room.quadrant(&r.move_by(100, &room).pos))
+        .for_each(|q| in_quadrant[q as usize] += 1);
+
+    in_quadrant.into_iter().product()
+}
+
+fn find_tree(robots: &[Robot]) -> Option<usize> {
+    let room = Room::for_robots(robots);
+    let mut robots = robots.to_vec();
+    for t in 0..10000 {
+        if might_be_tree(&robots) {
+            return Some(t);
+        }
+        robots = robots.into_iter().map(|r| r.move_by(1, &room)).collect();
+    }
+
+    None
+}
+
+pub struct Day14;
+
+impl Puzzle for Day14 {
+    type Input = Vec<Robot>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        read_problem(BufReader::new(input.as_bytes()))
+    }
+
+    fn part1(robots: &Self::Input) -> Answer {
+        safety_factor(robots).into()
+    }
+
+    fn part2(robots: &Self::Input) -> Answer {
+        find_tree(robots).into()
+    }
+}
+
+pub fn part1(input: &str) -> ParseResult<Answer> {
+    puzzle::solve_part1::<Day14>(input)
+}
+
+pub fn part2(input: &str) -> ParseResult<Answer> {
+    puzzle::solve_part2::<Day14>(input)
+}
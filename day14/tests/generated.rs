@@ -0,0 +1,13 @@
+use aoc_common::{
+    generate::{Params, Rng},
+    Answer,
+};
+
+#[test]
+fn generated_robots_line_up() {
+    for seed in 0..3 {
+        let input = day14::generate::input(&mut Rng::new(seed), &Params { size: 100, solvable: true });
+
+        assert!(matches!(day14::part2(&input), Ok(Answer::Number(_))), "seed {seed}");
+    }
+}
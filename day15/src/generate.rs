@@ -0,0 +1,37 @@
+//! Random warehouses and robot moves. `size` is the side of the warehouse, walls included.
+//! There are ten moves per cell of the side.
+
+use aoc_common::{
+    generate::{Params, Rng},
+    Grid, Point,
+};
+
+pub fn input(rng: &mut Rng, params: &Params) -> String {
+    let side = params.size.max(3);
+    let border = |p: Point| p.x == 0 || p.y == 0 || p.x as usize == side - 1 || p.y as usize == side - 1;
+    let mut map = Grid::new(side, side, '.');
+
+    for p in map.points().collect::<Vec<_>>() {
+        map[p] = if border(p) || rng.chance(0.05) {
+            '#'
+        } else if rng.chance(0.25) {
+            'O'
+        } else {
+            '.'
+        };
+    }
+
+    let robot = Point::from_row_col(rng.between(1, side as i64 - 2) as usize, rng.between(1, side as i64 - 2) as usize);
+    map[robot] = '@';
+
+    let mut text = map.rows().map(|r| r.iter().collect::<String>() + "\n").collect::<String>();
+    text.push('\n');
+
+    let moves = (0..side * 10).map(|_| *rng.pick(&['^', 'v', '<', '>'])).collect::<Vec<_>>();
+    for line in moves.chunks(70) {
+        text.extend(line);
+        text.push('\n');
+    }
+
+    text
+}
@@ -0,0 +1,187 @@
+pub mod generate;
+
+use std::io::{BufReader, Read};
+use aoc_common::{
+    error::numbered_lines,
+    puzzle,
+    render::{Animation, Canvas, Cell, Rgb, Sampler},
+    Answer, Direction, Grid, ParseError, ParseResult, Point, Puzzle,
+};
+
+fn direction_from(value: char) -> Option<Direction> {
+    match value {
+        '^' => Some(Direction::Up),
+        'v' => Some(Direction::Down),
+        '<' => Some(Direction::Left),
+        '>' => Some(Direction::Right),
+        _ => None,
+    }
+}
+
+/// Moves the robot, along with the boxes in front of it, unless they are blocked by a wall.
+/// Returns where the robot ends up.
+fn push(tiles: &mut Grid<Tile>, robot: Point, instr: Direction) -> Point {
+    let mut next_step = robot.step(instr);
+    let mut boxes_to_move = vec![];
+
+    while tiles[next_step] != Tile::Wall {
+        if tiles[next_step] == Tile::Box {
+            boxes_to_move.push(next_step);
+            next_step = next_step.step(instr);
+        } else {
+            while let Some(bx) = boxes_to_move.pop() {
+                tiles[bx] = Tile::Empty;
+                tiles[bx.step(instr)] = Tile::Box;
+            }
+            return robot.step(instr);
+        }
+    }
+
+    robot
+}
+
+fn gps_coordinate(p: &Point) -> i32 {
+    p.y * 100 + p.x
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Wall,
+    Box,
+    Empty,
+}
+
+const MOVES_PER_FRAME: usize = 10;
+
+pub struct Map {
+    tiles: Grid<Tile>,
+    robot: Point,
+}
+
+impl Map {
+    fn follow(&self, instructions: &[Direction]) -> Map {
+        let mut tiles = self.tiles.clone();
+        let mut robot = self.robot;
+
+        for &instr in instructions.iter() {
+            robot = push(&mut tiles, robot, instr);
+        }
+
+        Map {
+            tiles,
+            robot,
+        }
+    }
+
+    fn sum_coords(&self) -> i32 {
+        self.tiles.positions(|&t| t == Tile::Box).map(|b| gps_coordinate(&b)).sum::<i32>()
+    }
+
+    fn canvas(tiles: &Grid<Tile>, robot: Point) -> Canvas {
+        let mut canvas = Canvas::from_grid(tiles, |tile| match tile {
+            Tile::Box => Cell::new('O', Rgb::ORANGE),
+            Tile::Wall => Cell::new('#', Rgb::GREY),
+            Tile::Empty => Cell::EMPTY,
+        });
+
+        canvas.draw(robot, Cell::new('@', Rgb::GREEN));
+        canvas
+    }
+
+    // Same moves as follow(), drawing the warehouse every few of them
+    fn trace(&self, instructions: &[Direction]) -> Animation {
+        let mut tiles = self.tiles.clone();
+        let mut robot = self.robot;
+        let mut sampler = Sampler::new(MOVES_PER_FRAME);
+
+        for &instr in instructions.iter() {
+            sampler.offer(|| Map::canvas(&tiles, robot));
+            robot = push(&mut tiles, robot, instr);
+        }
+
+        sampler.keep(Map::canvas(&tiles, robot));
+        sampler.finish()
+    }
+}
+
+impl Map {
+    fn parse<R: Read>(stream: &mut BufReader<R>) -> ParseResult<Self> {
+        let raw = Grid::parse(stream, |c| match c {
+            '#' | 'O' | '@' | '.' => Ok(c),
+            _ => Err("'#', 'O', '@' or '.'".to_string()),
+        })?;
+
+        // The robot can't leave the warehouse as long as it's surrounded by walls
+        let on_border = |p: &Point| {
+            p.x == 0 || p.y == 0 || p.x as usize == raw.width() - 1 || p.y as usize == raw.height() - 1
+        };
+        if let Some(p) = raw.points().find(|p| on_border(p) && raw[*p] != '#') {
+            return Err(ParseError::new(p.y as usize + 1, p.x as usize + 1, "a wall around the warehouse").found(raw[p]));
+        }
+
+        let robot = raw.positions(|&c| c == '@').next()
+            .ok_or_else(|| ParseError::new(raw.height() + 1, 1, "a robot ('@') in the map").found(""))?;
+        let tiles = raw.map(|&c| match c {
+            '#' => Tile::Wall,
+            'O' => Tile::Box,
+            _ => Tile::Empty,
+        });
+
+        Ok(Map {
+            tiles,
+            robot,
+        })
+    }
+}
+
+fn read_map<R>(mut stream: BufReader<R>) -> ParseResult<(Map, Vec<Direction>)>
+where
+    R: Read
+{
+    let map = Map::parse(&mut stream)?;
+    // The map and the blank line after it were consumed already
+    let first_line = map.tiles.height() + 1;
+    let mut instructions = vec![];
+
+    for line in numbered_lines(stream) {
+        let (n, line) = line?;
+
+        for (col, c) in line.trim_end().chars().enumerate() {
+            let dir = direction_from(c)
+                .ok_or_else(|| ParseError::new(first_line + n, col + 1, "a move ('^', 'v', '<', '>')").found(c))?;
+            instructions.push(dir);
+        }
+    }
+
+    Ok((map, instructions))
+}
+
+pub struct Day15;
+
+impl Puzzle for Day15 {
+    type Input = (Map, Vec<Direction>);
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        read_map(BufReader::new(input.as_bytes()))
+    }
+
+    fn part1((map, instr): &Self::Input) -> Answer {
+        map.follow(instr).sum_coords().into()
+    }
+
+    fn part2(_input: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn render((map, instr): &Self::Input) -> Option<Animation> {
+        Some(map.trace(instr))
+    }
+}
+
+pub fn part1(input: &str) -> ParseResult<Answer> {
+    puzzle::solve_part1::<Day15>(input)
+}
+
+pub fn part2(input: &str) -> ParseResult<Answer> {
+    puzzle::solve_part2::<Day15>(input)
+}
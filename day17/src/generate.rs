@@ -0,0 +1,40 @@
+//! Random programs, shaped like the puzzle's: every round outputs a number computed from the
+//! lowest bits of register A, then shifts it three bits away, until it's zero. `size` is the
+//! number of rounds, up to 20.
+//!
+//! When solvable, there's a value of register A that makes the program output itself.
+
+use std::io::BufReader;
+
+use aoc_common::generate::{Params, Rng};
+
+use crate::{find_quine, read_input};
+
+fn program(rng: &mut Rng) -> String {
+    let (k1, k2) = (rng.between(0, 7), rng.between(0, 7));
+    let mut round = vec![
+        format!("2,4,1,{k1},7,5"),
+        format!("1,{k2},4,{}", rng.between(0, 7)),
+    ];
+    rng.shuffle(&mut round);
+
+    format!("{},0,3,5,5,3,0", round.join(","))
+}
+
+pub fn input(rng: &mut Rng, params: &Params) -> String {
+    let rounds = params.size.clamp(1, 20) as u32;
+    let register_a = rng.between(8i64.pow(rounds - 1), 8i64.pow(rounds) - 1);
+
+    loop {
+        let text = format!("Register A: {register_a}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n", program(rng));
+
+        if !params.solvable {
+            return text;
+        }
+
+        let (cpu, prog) = read_input(BufReader::new(text.as_bytes())).expect("Generated an invalid program");
+        if find_quine(&mut cpu.clone(), &prog).is_some() {
+            return text;
+        }
+    }
+}
@@ -0,0 +1,305 @@
+pub mod generate;
+
+use std::io::{BufReader, Read};
+use aoc_common::{
+    error::{column_of, numbered_lines, parse_at},
+    puzzle, Answer, ParseError, ParseResult, Puzzle,
+};
+
+#[derive(Debug)]
+enum Opcode {
+    Adv,
+    Bxl,
+    Bst,
+    Jnz,
+    Bxc,
+    Out,
+    Bdv,
+    Cdv,
+}
+
+impl Opcode {
+    fn takes_combo(&self) -> bool {
+        matches!(self, Opcode::Adv | Opcode::Bst | Opcode::Out | Opcode::Bdv | Opcode::Cdv)
+    }
+}
+
+impl From<u8> for Opcode {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Opcode::Adv,
+            1 => Opcode::Bxl,
+            2 => Opcode::Bst,
+            3 => Opcode::Jnz,
+            4 => Opcode::Bxc,
+            5 => Opcode::Out,
+            6 => Opcode::Bdv,
+            7 => Opcode::Cdv,
+            _ => panic!("Expected a value from 0 .. 7, not {}!", value)
+        }
+    }
+}
+
+const MAX_STEPS: usize = 100_000;
+
+#[derive(Debug, Clone)]
+pub struct Cpu {
+    register: Vec<i64>,
+    program: Vec<u8>,
+    trace: bool
+}
+
+impl Cpu {
+    fn new() -> Self {
+        Cpu {
+            register: vec![0; 3],
+            program: vec![],
+            trace: false,
+        }
+    }
+
+    #[allow(dead_code)]
+    fn set_trace(&mut self, status: bool) {
+        self.trace = status
+    }
+
+    fn initialize_regs(&mut self, regs: Vec<i64>) {
+        assert!(regs.len() == self.register.len());
+
+        self.register = regs
+    }
+
+    fn load_program(&mut self, program: Vec<u8>) {
+        self.program = program
+    }
+
+    fn get_combo(&self, lit: u8) -> i64 {
+        match lit {
+            0..4 => lit as i64,
+            4..7 => self.register[(lit - 4) as usize],
+            7 => panic!("Reserved combo literal cannot show in legal programs"),
+            _ => panic!("Illegal combo literal {lit}")
+        }
+    }
+
+    // A / 2^combo. Registers are never negative, so it's just a shift, which also copes with huge
+    // combo values instead of overflowing
+    fn divide_a(&self, combo: i64) -> i64 {
+        u32::try_from(combo).ok()
+            .and_then(|shift| self.register[0].checked_shr(shift))
+            .unwrap_or(0)
+    }
+
+    fn print_trace(&self, left: &str, ip: usize) {
+        if self.trace {
+            eprintln!("{left:-24} | IP: {ip:4} | A: {:-10} | B: {:-10} | C: {:-10}",
+                self.register[0],
+                self.register[1],
+                self.register[2]);
+        }
+    }
+
+    /// Runs the program until it halts. Gives up, with no output, on programs still running
+    /// after `MAX_STEPS` instructions: they most likely loop forever.
+    fn run_program(&mut self) -> Option<Vec<u8>> {
+        let mem_limit: usize = self.program.len() - 1;
+        let mut output = vec![];
+        let mut ip = 0usize;
+
+        self.print_trace("START", 0);
+        for _ in 0..MAX_STEPS {
+            if ip >= mem_limit {
+                // HALT, we'll read past the end of the program
+                return Some(output);
+            }
+
+            let opcode = Opcode::from(self.program[ip]);
+            let operand = self.program[ip + 1];
+
+            ip += 2;
+            match opcode {
+                Opcode::Adv => {
+                    // A = A / (2^combo)
+                    let combo = self.get_combo(operand);
+                    self.register[0] = self.divide_a(combo);
+                    self.print_trace(format!("adv {combo}({operand})").as_str(), ip);
+                }
+                Opcode::Bxl => {
+                    self.register[1] ^= operand as i64;
+                    self.print_trace(format!("bxl {operand}").as_str(), ip);
+                },
+                Opcode::Bst => {
+                    let combo = self.get_combo(operand);
+                    self.register[1] = combo % 8;
+                    self.print_trace(format!("bst {combo}({operand})").as_str(), ip);
+                }
+                Opcode::Jnz => {
+                    if self.register[0] != 0 {
+                        ip = operand as usize;
+                    }
+                    self.print_trace(format!("jnz {operand}").as_str(), ip);
+                }
+                Opcode::Bxc => {
+                    self.register[1] ^= self.register[2];
+                    self.print_trace("bxc", ip);
+                }
+                Opcode::Out => {
+                    let out = (self.get_combo(operand) % 8) as u8;
+                    output.push(out);
+                    self.print_trace(format!("out {out}({operand})").as_str(), ip);
+                }
+                Opcode::Bdv => {
+                    // B = A / (2^combo)
+                    let combo = self.get_combo(operand);
+                    self.register[1] = self.divide_a(combo);
+                    self.print_trace(format!("bdv {combo}({operand})").as_str(), ip);
+                },
+                Opcode::Cdv => {
+                    // C = A / (2^combo)
+                    let combo = self.get_combo(operand);
+                    self.register[2] = self.divide_a(combo);
+                    self.print_trace(format!("cdv {combo}({operand})").as_str(), ip);
+                },
+            }
+        }
+
+        None
+    }
+}
+
+fn parse_program(n: usize, line: &str, value: &str) -> ParseResult<Vec<u8>> {
+    let program = value
+        .split(",")
+        .map(|c| match parse_at::<u8>(n, line, c, "a 3-bit number") {
+            Ok(v) if v > 7 => Err(ParseError::new(n, column_of(line, c), "a 3-bit number").found(c)),
+            v => v,
+        })
+        .collect::<ParseResult<Vec<_>>>()?;
+
+    if program.len() < 2 || program.len() % 2 != 0 {
+        return Err(ParseError::new(n, column_of(line, value), "pairs of opcode and operand")
+            .found(format!("{} numbers", program.len())));
+    }
+
+    // Combo operand 7 is reserved. Checking here saves us from a panic halfway through the run
+    let pieces = value.split(",").collect::<Vec<_>>();
+    for (i, pair) in program.chunks(2).enumerate() {
+        if Opcode::from(pair[0]).takes_combo() && pair[1] == 7 {
+            return Err(ParseError::new(n, column_of(line, pieces[i * 2 + 1]), "a combo operand (0-6)").found("7"));
+        }
+    }
+
+    Ok(program)
+}
+
+fn read_input<R>(stream: BufReader<R>) -> ParseResult<(Cpu, String)>
+where 
+    R: Read
+{
+    let mut cpu = Cpu::new();
+    let mut registers = vec![0i64; 3];
+    let mut raw_string = None;
+
+    for line in numbered_lines(stream) {
+        let (n, line) = line?;
+        let line = line.trim_end();
+
+        if !line.is_empty() {
+            let (entry, value) = line.split_once(": ")
+                .ok_or_else(|| ParseError::new(n, 1, "an entry like 'Name: value'").found(line))?;
+
+            if entry.starts_with("Register") {
+                let reg_no = match entry.chars().last() {
+                    Some('A') => 0,
+                    Some('B') => 1,
+                    Some('C') => 2,
+                    _ => return Err(ParseError::new(n, 1, "register A, B or C").found(entry)),
+                };
+
+                registers[reg_no] = parse_at(n, line, value, "a register value")?;
+                if registers[reg_no] < 0 {
+                    return Err(ParseError::new(n, column_of(line, value), "a non-negative register value").found(value));
+                }
+            } else if entry == "Program" {
+                raw_string = Some(value.to_string());
+                cpu.load_program(parse_program(n, line, value)?);
+            } else {
+                return Err(ParseError::new(n, 1, "'Register X' or 'Program'").found(entry));
+            }
+        }
+    }
+
+    let raw_string = raw_string.ok_or_else(|| ParseError::new(1, 1, "a 'Program' entry").found(""))?;
+
+    cpu.initialize_regs(registers);
+    Ok((cpu, raw_string))
+}
+
+fn format_output(output: Vec<u8>) -> String {
+    output.into_iter()
+        .map(|n| char::from(n + 48).to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+// Finds the lowest value for register A that makes the program output itself
+fn find_quine(cpu: &mut Cpu, prog: &str) -> Option<i64> {
+    // Every candidate comes with the length of the output it gave, which must keep growing for
+    // the search to get anywhere
+    let mut candidates = vec![(0i64, 0usize)];
+    let mut solutions = vec![];
+
+    while let Some((p, matched)) = candidates.pop() {
+        // No room left for three more bits
+        if p > i64::MAX >> 3 {
+            continue;
+        }
+
+        let prefix: i64 = p << 3;
+        for i in 0..8 {
+            let value = prefix | i;
+            cpu.initialize_regs(vec![value, 0, 0]);
+            let new_output = format_output(cpu.run_program()?);
+
+            if new_output.len() > prog.len() {
+                break;
+            }
+
+            if new_output == prog {
+                solutions.push(value);
+            }
+            // A zero would take us back to the start of the search, and loop forever
+            else if value != 0 && new_output.len() > matched && prog.ends_with(new_output.as_str()) {
+                candidates.push((value, new_output.len()));
+            }
+        }
+    }
+
+    solutions.into_iter().min()
+}
+
+pub struct Day17;
+
+impl Puzzle for Day17 {
+    type Input = (Cpu, String);
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        read_input(BufReader::new(input.as_bytes()))
+    }
+
+    fn part1((cpu, _): &Self::Input) -> Answer {
+        cpu.clone().run_program().map(format_output).into()
+    }
+
+    fn part2((cpu, prog): &Self::Input) -> Answer {
+        find_quine(&mut cpu.clone(), prog).into()
+    }
+}
+
+pub fn part1(input: &str) -> ParseResult<Answer> {
+    puzzle::solve_part1::<Day17>(input)
+}
+
+pub fn part2(input: &str) -> ParseResult<Answer> {
+    puzzle::solve_part2::<Day17>(input)
+}
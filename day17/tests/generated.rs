@@ -0,0 +1,13 @@
+use aoc_common::{
+    generate::{Params, Rng},
+    Answer,
+};
+
+#[test]
+fn generated_programs_have_a_quine() {
+    for seed in 0..5 {
+        let input = day17::generate::input(&mut Rng::new(seed), &Params { size: 10, solvable: true });
+
+        assert!(matches!(day17::part2(&input), Ok(Answer::Number(_))), "seed {seed}:\n{input}");
+    }
+}
@@ -0,0 +1,43 @@
+use aoc_common::{Answer, Puzzle};
+use day17::Day17;
+use proptest::prelude::*;
+
+const OUT: u8 = 5;
+const JNZ: u8 = 3;
+
+// Opcode and operand pairs without jumps, so every instruction runs exactly once. Combo operands
+// never use the reserved 7.
+fn straight_program() -> impl Strategy<Value = Vec<u8>> {
+    let instruction = (0..8u8, 0..7u8)
+        .prop_filter("no jumps", |(opcode, _)| *opcode != JNZ)
+        .prop_map(|(opcode, operand)| [opcode, operand]);
+
+    prop::collection::vec(instruction, 1..20).prop_map(|i| i.concat())
+}
+
+fn to_input(registers: [i64; 3], program: &[u8]) -> String {
+    let program = program.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(",");
+
+    format!("Register A: {}\nRegister B: {}\nRegister C: {}\n\nProgram: {program}\n", registers[0], registers[1], registers[2])
+}
+
+proptest! {
+    #[test]
+    fn outputs_once_per_out_instruction(registers in prop::array::uniform3(0..i64::MAX), program in straight_program()) {
+        let input = Day17::parse(&to_input(registers, &program)).unwrap();
+        let outs = program.chunks(2).filter(|i| i[0] == OUT).count();
+
+        let Answer::Text(output) = Day17::part1(&input) else {
+            panic!("The output should be text");
+        };
+        let values = output.split(',').filter(|v| !v.is_empty()).collect::<Vec<_>>();
+
+        prop_assert_eq!(values.len(), outs);
+        prop_assert!(values.iter().all(|v| matches!(*v, "0" | "1" | "2" | "3" | "4" | "5" | "6" | "7")));
+    }
+
+    #[test]
+    fn rejects_garbage_without_panicking(input in "(Register [ABCD]: -?[0-9]{0,20}\n|Program: [0-9,]{0,20}\n|\\PC{0,10}\n){0,6}") {
+        let _ = Day17::parse(&input);
+    }
+}
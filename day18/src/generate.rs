@@ -0,0 +1,58 @@
+//! Random falling bytes in the puzzle's memory space. `size` is the number of bytes.
+//!
+//! When solvable, there's a way out after the first bytes fall, and there are enough bytes to
+//! cut it in the end (so there may be more than `size` of them).
+
+use aoc_common::{
+    generate::{Params, Rng},
+    Point,
+};
+
+use crate::MemorySpace;
+
+pub fn input(rng: &mut Rng, params: &Params) -> String {
+    let space = &MemorySpace::PUZZLE;
+    let (max_x, max_y) = space.limits;
+    let exit = Point { x: max_x, y: max_y };
+
+    let mut cells = (0..=max_y)
+        .flat_map(|y| (0..=max_x).map(move |x| Point { x, y }))
+        .filter(|&p| p != Point { x: 0, y: 0 } && p != exit)
+        .collect::<Vec<_>>();
+
+    let bytes = loop {
+        rng.shuffle(&mut cells);
+
+        if !params.solvable {
+            let mut bytes = cells[..params.size.clamp(1, cells.len())].to_vec();
+
+            // The example space is picked when all the bytes fit in it
+            let (example_x, example_y) = MemorySpace::EXAMPLE.limits;
+            if bytes.iter().all(|b| b.x <= example_x && b.y <= example_y) {
+                bytes[0] = Point { x: max_x, y: 0 };
+            }
+            break bytes;
+        }
+
+        let has_exit = |fallen: usize| {
+            let mut map = space.empty_map();
+            map.corrupt(&cells[..fallen]);
+            map.find_exit().is_some()
+        };
+
+        if !has_exit(space.fallen) {
+            continue;
+        }
+
+        // Find how many bytes cut the way out. Once all the cells fill up, it surely is.
+        let (mut good, mut bad) = (space.fallen, cells.len());
+        while bad - good > 1 {
+            let middle = (good + bad) / 2;
+            if has_exit(middle) { good = middle } else { bad = middle }
+        }
+
+        break cells[..bad.max(params.size).min(cells.len())].to_vec();
+    };
+
+    bytes.iter().map(|b| format!("{},{}\n", b.x, b.y)).collect()
+}
@@ -0,0 +1,258 @@
+pub mod generate;
+
+use std::{
+    collections::HashSet,
+    io::{BufReader, Read},
+    cmp::Reverse,
+};
+
+use aoc_common::{
+    error::{numbered_lines, parse_at},
+    puzzle,
+    render::{Animation, Canvas, Cell, Rgb, Sampler},
+    Answer, Grid, ParseError, ParseResult, Point, Puzzle,
+};
+use priority_queue::PriorityQueue;
+
+fn point_from(n: usize, value: &str) -> ParseResult<Point> {
+    let (raw_x, raw_y) = value
+        .split_once(',')
+        .ok_or_else(|| ParseError::new(n, 1, "a pair of coordinates like 'x,y'").found(value))?;
+
+    // Parsing as unsigned rejects negative coordinates, which would be out of the memory space
+    Ok(Point {
+        x: parse_at::<u16>(n, value, raw_x, "a coordinate")? as i32,
+        y: parse_at::<u16>(n, value, raw_y, "a coordinate")? as i32,
+    })
+}
+
+const VISITS_PER_FRAME: usize = 25;
+
+#[derive(Clone)]
+struct Map {
+    corrupted: Grid<bool>,
+}
+
+impl Map {
+    fn new(width: i32, height: i32) -> Self {
+        Map {
+            corrupted: Grid::new(width as usize, height as usize, false),
+        }
+    }
+
+    fn corrupt(&mut self, byte_coords: &[Point]) {
+        for &coords in byte_coords.iter() {
+            self.corrupted.set(coords, true);
+        }
+    }
+
+    fn generate_neighbors(&self, point: Point) -> Vec<Point> {
+        self.corrupted.neighbors4(point)
+            .filter(|&p| !self.corrupted[p])
+            .collect()
+    }
+
+    fn find_exit(&self) -> Option<usize> {
+        self.search(|_, _, _| {})
+    }
+
+    /// A* search from the top left corner to the bottom right one. `on_visit` is called with
+    /// every point about to be expanded, along with the visited points and the search front,
+    /// and with the target once it's reached.
+    fn search<F>(&self, mut on_visit: F) -> Option<usize>
+    where
+        F: FnMut(Point, &HashSet<Point>, &HashSet<Point>),
+    {
+        let origin = Point { x: 0, y: 0 };
+        let target = Point { x: self.corrupted.width() as i32 - 1, y: self.corrupted.height() as i32 - 1 };
+
+        let mut visited: HashSet<Point> = HashSet::new();
+        let mut front_scores = PriorityQueue::new();
+        front_scores.push(origin, Reverse((origin.manhattan(&target), 0usize)));
+        let mut front_set: HashSet<Point> = HashSet::from_iter(vec![origin]);
+
+        while let Some((current, rev_steps)) = front_scores.pop() {
+            let (_prio, current_steps) = rev_steps.0;
+
+            front_set.remove(&current);
+            on_visit(current, &visited, &front_set);
+
+            let all_neighbors = self.generate_neighbors(current);
+            visited.insert(current);
+
+            for neighbor in all_neighbors.into_iter() {
+                if neighbor == target {
+                    on_visit(target, &visited, &front_set);
+                    return Some(current_steps + 1)
+                }
+
+                let next_step = current_steps + 1;
+
+                if !visited.contains(&neighbor) {
+                    if front_set.contains(&neighbor) {
+                        let mut it = front_scores.iter_mut();
+                        let (_, prio) = it
+                            .find(|(p, _)| *p == &neighbor)
+                            .unwrap();
+
+                        let (distance, steps) = prio.0;
+
+                        if steps > next_step {
+                            *prio = Reverse((distance - steps + next_step, next_step))
+                        }
+                    } else {
+                        let d = neighbor.manhattan(&target);
+                        front_set.insert(neighbor);
+                        front_scores.push(neighbor, Reverse((d + next_step, next_step)));
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    fn canvas(&self, current: Point, visited: &HashSet<Point>, front: &HashSet<Point>) -> Canvas {
+        let mut canvas = Canvas::from_grid(&self.corrupted, |&corrupted| match corrupted {
+            true => Cell::new('#', Rgb::GREY),
+            false => Cell::EMPTY,
+        });
+
+        canvas.mark(visited, Cell::new('+', Rgb::BLUE));
+        canvas.mark(front, Cell::new('*', Rgb::YELLOW));
+        canvas.draw(current, Cell::new('X', Rgb::RED));
+        canvas
+    }
+
+    // The search of find_exit(), drawn every few expanded points
+    fn trace(&self) -> Animation {
+        let target = Point { x: self.corrupted.width() as i32 - 1, y: self.corrupted.height() as i32 - 1 };
+        let mut sampler = Sampler::new(VISITS_PER_FRAME);
+
+        self.search(|current, visited, front| {
+            if current == target {
+                sampler.keep(self.canvas(current, visited, front));
+            } else {
+                sampler.offer(|| self.canvas(current, visited, front));
+            }
+        });
+
+        sampler.finish()
+    }
+}
+
+fn dicotomic_search(initial_map: Map, bytes: &[Point], good: usize, bad: usize) -> Point {
+    let mut good = good;
+    let mut bad = bad;
+
+    while good < bad {
+        let next_attempt = (good + bad) / 2;
+        if good == next_attempt {
+            break;
+        }
+
+        let mut test_map = initial_map.clone();
+        test_map.corrupt(&bytes[..next_attempt]);
+
+        if test_map.find_exit().is_none() {
+            bad = next_attempt;
+        } else {
+            good = next_attempt;
+        }
+    }
+
+    bytes[bad - 1]
+}
+
+fn read_bytes<R>(stream: BufReader<R>) -> ParseResult<Vec<Point>>
+where 
+    R: Read
+{
+    numbered_lines(stream)
+        .map(|l| l.and_then(|(n, l)| point_from(n, l.trim_end())))
+        .collect()
+}
+
+/// Size of the memory space and number of bytes that fall before looking for the exit
+struct MemorySpace {
+    limits: (i32, i32),
+    fallen: usize,
+}
+
+impl MemorySpace {
+    const PUZZLE: MemorySpace = MemorySpace { limits: (70, 70), fallen: 1024 };
+    const EXAMPLE: MemorySpace = MemorySpace { limits: (6, 6), fallen: 12 };
+
+    // The example happens in a much smaller space. If all the bytes fit in it, that's the one
+    fn for_bytes(bytes: &[Point]) -> &'static MemorySpace {
+        let (max_x, max_y) = MemorySpace::EXAMPLE.limits;
+
+        if bytes.iter().all(|b| b.x <= max_x && b.y <= max_y) {
+            &MemorySpace::EXAMPLE
+        } else {
+            &MemorySpace::PUZZLE
+        }
+    }
+
+    fn empty_map(&self) -> Map {
+        Map::new(self.limits.0 + 1, self.limits.1 + 1)
+    }
+}
+
+pub struct Day18;
+
+impl Puzzle for Day18 {
+    type Input = Vec<Point>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        read_bytes(BufReader::new(input.as_bytes()))
+    }
+
+    fn part1(bytes: &Self::Input) -> Answer {
+        let space = MemorySpace::for_bytes(bytes);
+        let mut map = space.empty_map();
+
+        map.corrupt(&bytes[..space.fallen.min(bytes.len())]);
+
+        map.find_exit().into()
+    }
+
+    fn part2(bytes: &Self::Input) -> Answer {
+        let space = MemorySpace::for_bytes(bytes);
+        let mut good = (space.fallen - 1).min(bytes.len());
+
+        loop {
+            let next_attempt = (good * 2).min(bytes.len());
+            let mut next_map = space.empty_map();
+            next_map.corrupt(&bytes[..next_attempt]);
+
+            if next_map.find_exit().is_none() {
+                let needle = dicotomic_search(space.empty_map(), bytes, good, next_attempt);
+
+                return format!("{},{}", needle.x, needle.y).into();
+            } else if next_attempt == bytes.len() {
+                // All the bytes have fallen and there's still a way out
+                return Answer::Unsolved;
+            } else {
+                good = next_attempt;
+            }
+        }
+    }
+
+    fn render(bytes: &Self::Input) -> Option<Animation> {
+        let space = MemorySpace::for_bytes(bytes);
+        let mut map = space.empty_map();
+
+        map.corrupt(&bytes[..space.fallen.min(bytes.len())]);
+
+        Some(map.trace())
+    }
+}
+
+pub fn part1(input: &str) -> ParseResult<Answer> {
+    puzzle::solve_part1::<Day18>(input)
+}
+
+pub fn part2(input: &str) -> ParseResult<Answer> {
+    puzzle::solve_part2::<Day18>(input)
+}
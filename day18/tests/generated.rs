@@ -0,0 +1,24 @@
+use aoc_common::{
+    generate::{Params, Rng},
+    Answer,
+};
+
+#[test]
+fn generated_bytes_cut_the_way_out() {
+    for seed in 0..3 {
+        let input = day18::generate::input(&mut Rng::new(seed), &Params { size: 1024, solvable: true });
+
+        assert!(matches!(day18::part1(&input), Ok(Answer::Number(_))));
+        assert!(matches!(day18::part2(&input), Ok(Answer::Text(_))), "seed {seed}");
+    }
+}
+
+#[test]
+fn few_bytes_still_fall_in_the_puzzle_space() {
+    // Without care, the single byte of seed 61 would fit in the example space
+    for seed in 55..65 {
+        let input = day18::generate::input(&mut Rng::new(seed), &Params { size: 1, solvable: false });
+
+        assert_eq!(day18::part1(&input).unwrap(), Answer::Number(140), "seed {seed}");
+    }
+}
@@ -0,0 +1,48 @@
+//! Random towel patterns and designs. `size` is the number of designs.
+//!
+//! As in the puzzle, there are single-stripe towels of every colour but white. When solvable,
+//! every design can be made out of the towels. Otherwise about half of them are just random
+//! stripes, which may or may not be possible.
+
+use aoc_common::generate::{Params, Rng};
+
+const COLOURS: [char; 5] = ['w', 'u', 'b', 'r', 'g'];
+const PATTERNS: usize = 400;
+
+fn stripes(rng: &mut Rng, length: usize) -> String {
+    (0..length).map(|_| *rng.pick(&COLOURS)).collect()
+}
+
+pub fn input(rng: &mut Rng, params: &Params) -> String {
+    let mut patterns = vec!["u".to_string(), "b".to_string(), "r".to_string(), "g".to_string()];
+    while patterns.len() < PATTERNS {
+        let length = rng.between(2, 8) as usize;
+        let pattern = stripes(rng, length);
+
+        if !patterns.contains(&pattern) {
+            patterns.push(pattern);
+        }
+    }
+    rng.shuffle(&mut patterns);
+
+    let mut text = patterns.join(", ") + "\n\n";
+
+    for _ in 0..params.size {
+        let length = rng.between(20, 60) as usize;
+
+        let design = if params.solvable || rng.chance(0.5) {
+            let mut design = String::new();
+            while design.len() < length {
+                design.push_str(rng.pick(&patterns).as_str());
+            }
+            design
+        } else {
+            stripes(rng, length)
+        };
+
+        text.push_str(&design);
+        text.push('\n');
+    }
+
+    text
+}
@@ -0,0 +1,112 @@
+pub mod generate;
+
+use std::{
+    io::{BufReader, Read},
+    slice::Iter,
+};
+use aoc_common::{
+    error::{column_of, numbered_lines},
+    puzzle, Answer, ParseError, ParseResult, Puzzle,
+};
+
+#[derive(Clone)]
+struct Subproblem<'a> {
+    prefix_length: usize,
+    target: &'a str,
+    patterns: Iter<'a, String>,
+}
+
+impl<'a> Subproblem<'a> {
+    fn new(prefix_length: usize, target: &'a str, patterns: Iter<'a, String>) -> Self {
+        Subproblem {
+            prefix_length,
+            target,
+            patterns,
+        }
+    }
+}
+
+fn is_design_possible(design: &str, patterns: &[String]) -> bool {
+    let mut subproblems = vec![Subproblem::new(0, design, patterns.iter())];
+    // The rest of the design can't be made from a prefix already tried, however it was reached
+    let mut tried = vec![false; design.len()];
+
+    while let Some(mut current) = subproblems.pop() {
+        while let Some(pattern) = current.patterns.next() {
+            if current.target == pattern {
+                return true;
+            } else if current.target.starts_with(pattern) {
+                let sub_prefix = current.prefix_length + pattern.len();
+                if std::mem::replace(&mut tried[sub_prefix], true) {
+                    continue;
+                }
+                subproblems.push(current.clone());
+                subproblems.push(Subproblem {
+                    prefix_length: sub_prefix,
+                    target: &design[sub_prefix..],
+                    patterns: patterns.iter(),
+                });
+            }
+        }
+    }
+
+    false
+}
+
+fn read_input<R>(stream: BufReader<R>) -> ParseResult<(Vec<String>, Vec<String>)>
+where 
+    R: Read
+{
+    let mut lines = numbered_lines(stream);
+
+    let (_, first) = lines.next()
+        .unwrap_or_else(|| Err(ParseError::new(1, 1, "a list of towel patterns").found("")))?;
+    let first = first.trim_end();
+    let patterns = first
+        .split(", ")
+        .map(|s| if s.is_empty() {
+            Err(ParseError::new(1, column_of(first, s), "a towel pattern").found(""))
+        } else {
+            Ok(s.to_string())
+        })
+        .collect::<ParseResult<Vec<_>>>()?;
+
+    if let Some(blank) = lines.next() {
+        let (n, blank) = blank?;
+        if !blank.trim().is_empty() {
+            return Err(ParseError::new(n, 1, "a blank line after the patterns").found(blank));
+        }
+    }
+
+    let designs = lines
+        .map(|l| l.map(|(_, l)| l.trim_end().to_string()))
+        .collect::<ParseResult<Vec<_>>>()?;
+
+    Ok((patterns, designs))
+}
+
+pub struct Day19;
+
+impl Puzzle for Day19 {
+    type Input = (Vec<String>, Vec<String>);
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        read_input(BufReader::new(input.as_bytes()))
+    }
+
+    fn part1((patterns, designs): &Self::Input) -> Answer {
+        designs.iter().filter(|d| is_design_possible(d, patterns)).count().into()
+    }
+
+    fn part2(_input: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+}
+
+pub fn part1(input: &str) -> ParseResult<Answer> {
+    puzzle::solve_part1::<Day19>(input)
+}
+
+pub fn part2(input: &str) -> ParseResult<Answer> {
+    puzzle::solve_part2::<Day19>(input)
+}
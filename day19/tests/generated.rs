@@ -0,0 +1,10 @@
+use aoc_common::generate::{Params, Rng};
+
+#[test]
+fn generated_designs_are_possible() {
+    for seed in 0..5 {
+        let input = day19::generate::input(&mut Rng::new(seed), &Params { size: 20, solvable: true });
+
+        assert_eq!(day19::part1(&input).unwrap().to_string(), "20");
+    }
+}
@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_common::Puzzle;
+use day01::Day01;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    if let Ok(input) = Day01::parse(data) {
+        Day01::part1(&input);
+        Day01::part2(&input);
+    }
+});
@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_common::Puzzle;
+use day02::Day02;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    if let Ok(input) = Day02::parse(data) {
+        Day02::part1(&input);
+        Day02::part2(&input);
+    }
+});
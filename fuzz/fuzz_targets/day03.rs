@@ -0,0 +1,13 @@
+#![no_main]
+
+use aoc_common::Puzzle;
+use day03::Day03;
+use libfuzzer_sys::fuzz_target;
+
+// The instructions are only recognised while solving, so both parts go through the extractor
+fuzz_target!(|data: &str| {
+    if let Ok(input) = Day03::parse(data) {
+        Day03::part1(&input);
+        Day03::part2(&input);
+    }
+});
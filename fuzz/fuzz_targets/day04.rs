@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_common::Puzzle;
+use day04::Day04;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    if let Ok(input) = Day04::parse(data) {
+        Day04::part1(&input);
+        Day04::part2(&input);
+    }
+});
@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_common::Puzzle;
+use day05::Day05;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    if let Ok(input) = Day05::parse(data) {
+        Day05::part1(&input);
+        Day05::part2(&input);
+    }
+});
@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_common::Puzzle;
+use day06::Day06;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    if let Ok(input) = Day06::parse(data) {
+        Day06::part1(&input);
+        Day06::part2(&input);
+    }
+});
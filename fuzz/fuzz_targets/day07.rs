@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_common::Puzzle;
+use day07::Day07;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    if let Ok(input) = Day07::parse(data) {
+        Day07::part1(&input);
+        Day07::part2(&input);
+    }
+});
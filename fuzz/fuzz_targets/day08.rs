@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_common::Puzzle;
+use day08::Day08;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    if let Ok(input) = Day08::parse(data) {
+        Day08::part1(&input);
+        Day08::part2(&input);
+    }
+});
@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_common::Puzzle;
+use day09::{Day09, Fragmentation};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    if let Ok(filesys) = Day09::parse(data) {
+        filesys.compact(Fragmentation::Allow).checksum();
+        filesys.compact(Fragmentation::DontAllow).checksum();
+    }
+});
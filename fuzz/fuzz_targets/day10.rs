@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_common::Puzzle;
+use day10::Day10;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    if let Ok(input) = Day10::parse(data) {
+        Day10::part1(&input);
+        Day10::part2(&input);
+    }
+});
@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_common::Puzzle;
+use day11::Day11;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    if let Ok(input) = Day11::parse(data) {
+        Day11::part1(&input);
+        Day11::part2(&input);
+    }
+});
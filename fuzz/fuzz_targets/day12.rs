@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_common::Puzzle;
+use day12::Day12;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    if let Ok(input) = Day12::parse(data) {
+        Day12::part1(&input);
+        Day12::part2(&input);
+    }
+});
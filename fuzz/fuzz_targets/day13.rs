@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_common::Puzzle;
+use day13::Day13;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    if let Ok(input) = Day13::parse(data) {
+        Day13::part1(&input);
+        Day13::part2(&input);
+    }
+});
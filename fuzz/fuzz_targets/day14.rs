@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_common::Puzzle;
+use day14::Day14;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    if let Ok(input) = Day14::parse(data) {
+        Day14::part1(&input);
+        Day14::part2(&input);
+    }
+});
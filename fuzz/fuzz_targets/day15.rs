@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_common::Puzzle;
+use day15::Day15;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    if let Ok(input) = Day15::parse(data) {
+        Day15::part1(&input);
+        Day15::part2(&input);
+    }
+});
@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_common::Puzzle;
+use day17::Day17;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    if let Ok(input) = Day17::parse(data) {
+        Day17::part1(&input);
+        Day17::part2(&input);
+    }
+});
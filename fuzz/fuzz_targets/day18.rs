@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_common::Puzzle;
+use day18::Day18;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    if let Ok(input) = Day18::parse(data) {
+        Day18::part1(&input);
+        Day18::part2(&input);
+    }
+});
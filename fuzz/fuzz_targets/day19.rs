@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_common::Puzzle;
+use day19::Day19;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    if let Ok(input) = Day19::parse(data) {
+        Day19::part1(&input);
+        Day19::part2(&input);
+    }
+});